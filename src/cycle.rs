@@ -0,0 +1,146 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+// The shape of a sequence x, f(x), f(f(x)), ... over finitely many states: after `start` steps
+// it runs through a cycle of `length` states for ever.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Cycle {
+    pub start: usize,
+    pub length: usize,
+}
+
+impl Cycle {
+    // The first step at which the state after n steps occurs.
+    pub fn reduce(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.length
+        }
+    }
+}
+
+// Find the cycle by remembering every state seen, which are returned in order as well.
+#[allow(unused)]
+pub fn find_cycle<T, F>(initial: T, mut step: F) -> (Cycle, Vec<T>)
+where
+    T: Clone + Eq + Hash,
+    F: FnMut(&T) -> T,
+{
+    let mut seen = HashMap::new();
+    let mut history = vec![];
+    let mut state = initial;
+    loop {
+        if let Some(&start) = seen.get(&state) {
+            let length = history.len() - start;
+            return (Cycle { start, length }, history);
+        }
+        seen.insert(state.clone(), history.len());
+        let next = step(&state);
+        history.push(state);
+        state = next;
+    }
+}
+
+// The state after n steps, skipping ahead once the cycle is found.
+pub fn nth_state<T, F>(initial: T, mut step: F, n: usize) -> T
+where
+    T: Clone + Eq + Hash,
+    F: FnMut(&T) -> T,
+{
+    let mut seen = HashMap::new();
+    let mut history = vec![];
+    let mut state = initial;
+    while history.len() < n {
+        if let Some(&start) = seen.get(&state) {
+            let cycle = Cycle {
+                start,
+                length: history.len() - start,
+            };
+            return history.swap_remove(cycle.reduce(n));
+        }
+        seen.insert(state.clone(), history.len());
+        let next = step(&state);
+        history.push(state);
+        state = next;
+    }
+    state
+}
+
+// Find the cycle with Brent's algorithm, which only keeps two states around, at the cost of
+// stepping through the sequence about three times.
+#[allow(unused)]
+pub fn find_cycle_brent<T, F>(initial: T, mut step: F) -> Cycle
+where
+    T: Clone + Eq,
+    F: FnMut(&T) -> T,
+{
+    // Find the length by moving the tortoise to the hare at every power of two.
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(&initial);
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = step(&hare);
+        length += 1;
+    }
+    // Then the start, where two states a length apart first meet.
+    let mut tortoise = initial.clone();
+    let mut hare = (0..length).fold(initial, |state, _| step(&state));
+    let mut start = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+    Cycle { start, length }
+}
+
+// The state after n steps, for states too large to remember.
+#[allow(unused)]
+pub fn nth_state_brent<T, F>(initial: T, mut step: F, n: usize) -> T
+where
+    T: Clone + Eq,
+    F: FnMut(&T) -> T,
+{
+    let cycle = find_cycle_brent(initial.clone(), &mut step);
+    (0..cycle.reduce(n)).fold(initial, |state, _| step(&state))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn step(x: &u64) -> u64 {
+        (x * x + 1) % 1009
+    }
+
+    #[test]
+    fn cycle() {
+        for initial in 0..50 {
+            let (cycle, history) = find_cycle(initial, step);
+            assert_eq!(find_cycle_brent(initial, step), cycle);
+            assert_eq!(history.len(), cycle.start + cycle.length);
+            assert_eq!(step(history.last().unwrap()), history[cycle.start]);
+            let mut state = initial;
+            for n in 0..100 {
+                assert_eq!(nth_state(initial, step, n), state);
+                assert_eq!(nth_state_brent(initial, step, n), state);
+                state = step(&state);
+            }
+        }
+        assert_eq!(
+            find_cycle(0, |x| (x + 1) % 5).0,
+            Cycle {
+                start: 0,
+                length: 5
+            }
+        );
+        assert_eq!(find_cycle_brent(7, |x| x / 2).reduce(1000000000), 3);
+    }
+}
@@ -21,6 +21,9 @@ pub trait Day {
     fn tag(&self) -> &str;
     fn part1(&self, _input: &dyn Fn() -> Box<dyn io::Read>) {}
     fn part2(&self, _input: &dyn Fn() -> Box<dyn io::Read>) {}
+    fn dot(&self, _input: &dyn Fn() -> Box<dyn io::Read>) {}
+    fn svg(&self, _input: &dyn Fn() -> Box<dyn io::Read>) {}
+    fn report(&self, _input: &dyn Fn() -> Box<dyn io::Read>) {}
 }
 
 pub struct Utils;
@@ -53,3 +56,24 @@ impl Utils {
             .collect()
     }
 }
+
+#[cfg(test)]
+impl Utils {
+    // A pseudo-random grid of characters drawn evenly from the palette, repeating a character to
+    // make it more likely, for cross-checking against naive solutions in tests.
+    pub fn random_grid(mut seed: u64, width: usize, height: usize, palette: &str) -> String {
+        let palette = palette.chars().collect_vec();
+        (0..height)
+            .map(|_| {
+                (0..width)
+                    .map(|_| {
+                        seed = seed
+                            .wrapping_mul(6364136223846793005)
+                            .wrapping_add(1442695040888963407);
+                        palette[(seed >> 33) as usize % palette.len()]
+                    })
+                    .collect::<String>()
+            })
+            .join("\n")
+    }
+}
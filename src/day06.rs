@@ -1,8 +1,10 @@
 use crate::day::*;
+use num::{BigUint, One, Zero};
+use std::ops::RangeInclusive;
 
 pub struct Day06 {}
 
-type Output = usize;
+type Output = BigUint;
 
 impl Day for Day06 {
     fn tag(&self) -> &str {
@@ -29,18 +31,54 @@ impl Day06 {
             .collect::<BoxResult<Vec<_>>>()
     }
 
-    fn process(spec: Vec<Vec<Output>>) -> BoxResult<Output> {
+    fn beats(duration: &Output, record: &Output, press: &Output) -> bool {
+        press <= duration && (duration - press) * press > *record
+    }
+
+    // The button presses beating the record are those strictly between the roots of
+    // press * (duration - press) = record, i.e. (duration -/+ sqrt(duration^2 - 4 * record)) / 2.
+    // The integer square root may leave the bound off by one, so nudge it onto the first
+    // winning press.  The interval is symmetric around duration / 2.
+    fn winning(duration: &Output, record: &Output) -> Option<RangeInclusive<Output>> {
+        let square = duration * duration;
+        let four_record = record * 4u32;
+        if square <= four_record {
+            return None;
+        }
+        let root = (square - four_record).sqrt();
+        let mut first = (duration - root.min(duration.clone())) / 2u32;
+        while !first.is_zero() && Self::beats(duration, record, &(&first - 1u32)) {
+            first -= 1u32;
+        }
+        while first <= duration / 2u32 && !Self::beats(duration, record, &first) {
+            first += 1u32;
+        }
+        let last = duration - &first;
+        (first <= last).then_some(first..=last)
+    }
+
+    // The winning presses for every race, or none if the record cannot be beaten.
+    fn intervals(spec: Vec<Vec<Output>>) -> BoxResult<Vec<Option<RangeInclusive<Output>>>> {
         let [ref time, ref distance] = spec[..] else {
             return Err(AocError.into());
         };
-        let races = time.iter().zip(distance.iter()).collect_vec();
-        Ok(races
+        if time.len() != distance.len() {
+            return Err(AocError.into());
+        }
+        Ok(time
+            .iter()
+            .zip(distance.iter())
+            .map(|(duration, record)| Self::winning(duration, record))
+            .collect_vec())
+    }
+
+    fn process(spec: Vec<Vec<Output>>) -> BoxResult<Output> {
+        Ok(Self::intervals(spec)?
             .into_iter()
-            .map(|(&duration, &record)| {
-                (1..duration)
-                    .map(|press| (duration - press) * press)
-                    .filter(|&distance| distance > record)
-                    .count()
+            .map(|interval| {
+                interval.map_or_else(Output::zero, |interval| {
+                    interval.end() - interval.start() + Output::one()
+                })
             })
             .product::<Output>())
     }
@@ -72,8 +110,8 @@ impl Day06 {
 mod tests {
     use super::*;
 
-    fn test1(s: &str, f: Output) {
-        assert_eq!(Day06 {}.part1_impl(&mut s.as_bytes()).ok(), Some(f));
+    fn test1(s: &str, f: usize) {
+        assert_eq!(Day06 {}.part1_impl(&mut s.as_bytes()).ok(), Some(f.into()));
     }
 
     #[test]
@@ -85,8 +123,8 @@ Distance:  9  40  200",
         );
     }
 
-    fn test2(s: &str, f: Output) {
-        assert_eq!(Day06 {}.part2_impl(&mut s.as_bytes()).ok(), Some(f));
+    fn test2(s: &str, f: usize) {
+        assert_eq!(Day06 {}.part2_impl(&mut s.as_bytes()).ok(), Some(f.into()));
     }
 
     #[test]
@@ -97,4 +135,63 @@ Distance:  9  40  200",
             71503,
         );
     }
+
+    fn interval(first: u128, last: u128) -> Option<RangeInclusive<Output>> {
+        Some(Output::from(first)..=Output::from(last))
+    }
+
+    #[test]
+    fn intervals() {
+        let spec = Day06::parse(
+            &mut "Time: 7 15 30 4 5\nDistance: 9 40 200 4 5".as_bytes(),
+            |s| {
+                s.split_whitespace()
+                    .skip(1)
+                    .map(|d| d.parse::<Output>().map_err(Into::into))
+                    .collect::<BoxResult<Vec<_>>>()
+            },
+        )
+        .unwrap();
+        // A press of 2 in the 4 ms race only ties the record.
+        assert_eq!(
+            Day06::intervals(spec).unwrap(),
+            vec![
+                interval(2, 5),
+                interval(4, 11),
+                interval(11, 19),
+                None,
+                interval(2, 3)
+            ]
+        );
+    }
+
+    #[test]
+    fn large() {
+        // The winning presses of a race against every brute forced count.
+        for duration in 0u128..60 {
+            for record in 0..duration * duration / 4 + 2 {
+                let count = (0..=duration)
+                    .filter(|press| press * (duration - press) > record)
+                    .count();
+                let interval = Day06::winning(&duration.into(), &record.into());
+                assert_eq!(
+                    interval.map_or(0, |interval| {
+                        usize::try_from(interval.end() - interval.start()).unwrap() + 1
+                    }),
+                    count
+                );
+            }
+        }
+        // Far beyond 64 bits.
+        let duration = Output::from(10u32).pow(30) + 1u32;
+        let record = Output::from(10u32).pow(58);
+        let interval = Day06::winning(&duration, &record).unwrap();
+        assert!(Day06::beats(&duration, &record, interval.start()));
+        assert!(!Day06::beats(
+            &duration,
+            &record,
+            &(interval.start() - 1u32)
+        ));
+        assert_eq!(interval.start() + interval.end(), duration);
+    }
 }
@@ -1,5 +1,7 @@
 use crate::day::*;
 use num::integer::lcm;
+use petgraph::dot::Dot;
+use petgraph::graphmap::DiGraphMap;
 use regex::Regex;
 use std::collections::HashMap;
 
@@ -19,6 +21,13 @@ impl Day for Day08 {
     fn part2(&self, input: &dyn Fn() -> Box<dyn io::Read>) {
         println!("{:?}", self.part2_impl(&mut *input()));
     }
+
+    fn dot(&self, input: &dyn Fn() -> Box<dyn io::Read>) {
+        match self.dot_impl(&mut *input()) {
+            Ok(dot) => print!("{}", dot),
+            Err(e) => println!("{:?}", e),
+        }
+    }
 }
 
 lazy_static! {
@@ -90,6 +99,33 @@ impl Day08 {
             .or_else(|count| count)
     }
 
+    // Render the node map in Graphviz DOT format, edges labelled with the step taking them.
+    // Start nodes (ending in A) and end nodes (ending in Z) are highlighted.
+    fn dot_impl(&self, input: &mut dyn io::Read) -> BoxResult<String> {
+        let (_, nodes) = Self::parse(input)?;
+        let mut graph = DiGraphMap::new();
+        for (node, (left, right)) in &nodes {
+            if left == right {
+                graph.add_edge(node.as_str(), left.as_str(), "LR");
+            } else {
+                graph.add_edge(node.as_str(), left.as_str(), "L");
+                graph.add_edge(node.as_str(), right.as_str(), "R");
+            }
+        }
+        Ok(format!(
+            "{}",
+            Dot::with_attr_getters(&graph, &[], &|_, _| String::new(), &|_, (node, _)| if node
+                .ends_with('A')
+            {
+                "style = filled, fillcolor = green".to_string()
+            } else if node.ends_with('Z') {
+                "style = filled, fillcolor = red".to_string()
+            } else {
+                String::new()
+            },)
+        ))
+    }
+
     fn part2_impl(&self, input: &mut dyn io::Read) -> BoxResult<Output> {
         let (steps, map) = Self::parse(input)?;
         let a_nodes = map
@@ -177,4 +213,23 @@ XXX = (XXX, XXX)",
             6,
         );
     }
+
+    #[test]
+    fn dot() {
+        let dot = Day08 {}
+            .dot_impl(
+                &mut "LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)"
+                    .as_bytes(),
+            )
+            .unwrap();
+        assert!(dot.starts_with("digraph {"));
+        assert!(dot.contains("label = \"AAA\" style = filled, fillcolor = green"));
+        assert!(dot.contains("label = \"ZZZ\" style = filled, fillcolor = red"));
+        assert_eq!(dot.matches("label = \"LR\"").count(), 2);
+        assert_eq!(dot.matches(" -> ").count(), 4);
+    }
 }
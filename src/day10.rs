@@ -1,6 +1,7 @@
 use crate::day::*;
-use std::collections::hash_map::Entry::Vacant;
-use std::collections::HashMap;
+use crate::polygon::Polygon;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
 
 pub struct Day10 {}
 
@@ -18,6 +19,13 @@ impl Day for Day10 {
     fn part2(&self, input: &dyn Fn() -> Box<dyn io::Read>) {
         println!("{:?}", self.part2_impl(&mut *input()));
     }
+
+    fn report(&self, input: &dyn Fn() -> Box<dyn io::Read>) {
+        match self.report_impl(&mut *input()) {
+            Ok(report) => println!("{}", report),
+            Err(e) => println!("{:?}", e),
+        }
+    }
 }
 
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
@@ -32,6 +40,15 @@ enum Dir {
 }
 
 impl Dir {
+    fn opposite(self) -> Self {
+        match self {
+            Dir::East => Dir::West,
+            Dir::South => Dir::North,
+            Dir::West => Dir::East,
+            Dir::North => Dir::South,
+        }
+    }
+
     // Check if a part reachable in this direction is valid to connect to.
     fn valid(self, b: u8) -> bool {
         match self {
@@ -81,142 +98,266 @@ impl Coord {
     }
 }
 
+#[derive(Debug)]
+enum LoopError {
+    NoStart,
+    SeveralStarts,
+    // The start connects to more than two neighbours, in these directions.
+    AmbiguousStart(Vec<Dir>),
+    StartNotOnLoop,
+}
+
+impl fmt::Display for LoopError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LoopError::NoStart => write!(f, "the map has no start"),
+            LoopError::SeveralStarts => write!(f, "the map has more than one start"),
+            LoopError::AmbiguousStart(dirs) => {
+                write!(
+                    f,
+                    "the start connects to {} neighbours: {:?}",
+                    dirs.len(),
+                    dirs
+                )
+            }
+            LoopError::StartNotOnLoop => write!(f, "the start is not on a closed loop"),
+        }
+    }
+}
+
+impl error::Error for LoopError {}
+
+// The pipes making up a closed loop, in order.
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct PipeLoop {
+    cells: Vec<Coord>,
+}
+
+impl PipeLoop {
+    fn polygon(&self) -> Polygon {
+        Polygon::new(
+            self.cells
+                .iter()
+                .map(|&Coord(x, y)| (x as isize, y as isize))
+                .collect_vec(),
+        )
+    }
+}
+
 impl Day10 {
     fn parse(input: &mut dyn io::Read) -> BoxResult<(HashMap<Coord, u8>, Coord)> {
-        io::BufReader::new(input)
-            .lines()
-            .enumerate()
-            .try_fold(HashMap::new(), |mut map, (y, rs)| {
-                let _ = rs.map_err(|_| AocError).map(|s| {
-                    s.bytes().enumerate().for_each(|(x, b)| {
-                        map.insert(Coord(x, y), b);
-                    })
-                });
-                Ok(map)
-            })
-            .and_then(|map| {
-                Ok((
-                    map.clone(), // XXX
-                    map.iter()
-                        .find(|(_, &b)| b == b'S')
-                        .map(|(c, _)| *c)
-                        .ok_or(AocError)?,
-                ))
-            })
+        let map = io::BufReader::new(input).lines().enumerate().try_fold(
+            HashMap::new(),
+            |mut map, (y, rs)| {
+                for (x, b) in rs?.bytes().enumerate() {
+                    map.insert(Coord(x, y), b);
+                }
+                BoxResult::Ok(map)
+            },
+        )?;
+        let start = map
+            .iter()
+            .filter(|(_, &b)| b == b'S')
+            .map(|(c, _)| *c)
+            .at_most_one()
+            .map_err(|_| LoopError::SeveralStarts)?
+            .ok_or(LoopError::NoStart)?;
+        Ok((map, start))
+    }
+
+    // Anything outside the map counts as ground.
+    fn tile(map: &HashMap<Coord, u8>, c: Coord) -> u8 {
+        map.get(&c).copied().unwrap_or(b'.')
     }
 
-    fn start_dir(map: &HashMap<Coord, u8>, c: Coord) -> BoxResult<Dir> {
+    // The directions a part connects to.
+    fn exits(b: u8) -> Vec<Dir> {
         [Dir::East, Dir::South, Dir::West, Dir::North]
             .into_iter()
-            .find(|&dir| {
-                let &b = map.get(&c.walk(dir).unwrap()).unwrap(); // XXX
-                dir.valid(b)
-            })
-            .ok_or(AocError.into())
+            .filter(|dir| dir.opposite().valid(b))
+            .collect_vec()
     }
 
     // Infer a loop part given its neighbours.
     fn infer(map: &mut HashMap<Coord, u8>, start: &Coord) -> BoxResult<()> {
-        let v = [Dir::East, Dir::South, Dir::West, Dir::North]
+        let dirs = [Dir::East, Dir::South, Dir::West, Dir::North]
             .into_iter()
-            .map(|dir| {
-                if let Some(neighbour) = start.walk(dir) {
-                    let &b = map.get(&neighbour).unwrap(); // XXX
-                    dir.valid(b)
-                } else {
-                    false
-                }
+            .filter(|&dir| {
+                start
+                    .walk(dir)
+                    .is_some_and(|neighbour| dir.valid(Self::tile(map, neighbour)))
             })
-            .collect::<Vec<bool>>();
-        *map.get_mut(start).ok_or(AocError)? = match v[..] {
-            [true, true, false, false] => Ok(b'F'),
-            [true, false, true, false] => Ok(b'-'),
-            [true, false, false, true] => Ok(b'L'),
-            [false, true, true, false] => Ok(b'7'),
-            [false, true, false, true] => Ok(b'|'),
-            [false, false, true, true] => Ok(b'J'),
-            _ => Err(AocError),
-        }?;
+            .collect_vec();
+        *map.get_mut(start).ok_or(AocError)? = match dirs[..] {
+            [Dir::East, Dir::South] => b'F',
+            [Dir::East, Dir::West] => b'-',
+            [Dir::East, Dir::North] => b'L',
+            [Dir::South, Dir::West] => b'7',
+            [Dir::South, Dir::North] => b'|',
+            [Dir::West, Dir::North] => b'J',
+            _ if dirs.len() > 2 => Err(LoopError::AmbiguousStart(dirs))?,
+            _ => Err(LoopError::StartNotOnLoop)?,
+        };
         Ok(())
     }
 
-    fn detect_loop(map: &HashMap<Coord, u8>, start: Coord) -> BoxResult<Vec<Coord>> {
-        let dir = Self::start_dir(map, start)?;
-        let mut seen = HashMap::new();
-        seen.insert(start, 0);
-        let seen = (1 as Output..)
-            .try_fold((start, dir, seen), |(c, dir, mut seen), i| {
-                let next = c.walk(dir).ok_or(Err(AocError))?;
-                if let Vacant(e) = seen.entry(next) {
-                    e.insert(i);
-                    let &b = map.get(&next).ok_or(AocError).map_err(Err)?;
-                    Ok((next, dir.walk(b).unwrap(), seen)) // XXX
-                } else {
-                    Err(Ok(seen))
-                }
-            })
-            .and(Err(Err(AocError)))
-            .or_else(|rv| rv)?;
-        Ok(seen
-            .into_iter()
-            .sorted_by_key(|&(_, i)| i)
-            .map(|(c, _)| c)
-            .collect_vec())
+    // Follow the pipes from a part until back there, or until they lead nowhere.  Either way,
+    // return the cells passed, and whether they close a loop.  As every part has two exits, a
+    // path that does not lead back cannot run into a loop either.
+    fn follow(map: &HashMap<Coord, u8>, start: Coord) -> (Vec<Coord>, bool) {
+        let mut cells = vec![start];
+        let Some(&(mut dir)) = Self::exits(Self::tile(map, start)).first() else {
+            return (cells, false);
+        };
+        let mut c = start;
+        loop {
+            let Some(next) = c.walk(dir) else {
+                return (cells, false);
+            };
+            c = next;
+            let Ok(next) = dir.walk(Self::tile(map, c)) else {
+                return (cells, false);
+            };
+            if c == start {
+                return (cells, true);
+            }
+            dir = next;
+            cells.push(c);
+        }
     }
 
-    fn part1_impl(&self, input: &mut dyn io::Read) -> BoxResult<Output> {
-        let (map, start) = Self::parse(input)?;
-        Ok(Self::detect_loop(&map, start)?.len() / 2)
+    // Every closed loop of pipes, in the order of their first cell read row by row.
+    fn loops(map: &HashMap<Coord, u8>) -> Vec<PipeLoop> {
+        let mut seen = HashSet::new();
+        let mut loops = vec![];
+        for &c in map.keys().sorted_by_key(|c| (c.1, c.0)) {
+            if seen.contains(&c) {
+                continue;
+            }
+            let (cells, closed) = Self::follow(map, c);
+            seen.extend(cells.iter().copied());
+            if closed {
+                loops.push(PipeLoop { cells });
+            }
+        }
+        loops
     }
 
-    fn part2_impl(&self, input: &mut dyn io::Read) -> BoxResult<Output> {
+    // The map with the start replaced by its part, all its loops, and the index of the one
+    // through the start.
+    fn survey(input: &mut dyn io::Read) -> BoxResult<(HashMap<Coord, u8>, Vec<PipeLoop>, usize)> {
         let (mut map, start) = Self::parse(input)?;
         Self::infer(&mut map, &start)?;
-        let v = Self::detect_loop(&map, start)?;
-        // Erase all junk
-        map.iter_mut().for_each(|(c, b)| {
-            if !v.contains(c) {
-                *b = b'.'
-            }
-        });
-        let &min_x = map.iter().map(|(Coord(x, _), _)| x).min().ok_or(AocError)?;
-        let &max_x = map.iter().map(|(Coord(x, _), _)| x).max().ok_or(AocError)?;
-        let &min_y = map.iter().map(|(Coord(_, y), _)| y).min().ok_or(AocError)?;
-        let &max_y = map.iter().map(|(Coord(_, y), _)| y).max().ok_or(AocError)?;
-        Ok((min_y..=max_y).fold(0 as Output, |n, y| {
-            (min_x..=max_x)
-                .fold(
-                    (n, false, None),
-                    |(mut n, mut is_inside, mut horizontal_from_north), x| {
+        let loops = Self::loops(&map);
+        let i = loops
+            .iter()
+            .position(|pipe_loop| pipe_loop.cells.contains(&start))
+            .ok_or(LoopError::StartNotOnLoop)?;
+        Ok((map, loops, i))
+    }
+
+    // The loop through the start, starting there.
+    fn analyse(input: &mut dyn io::Read) -> BoxResult<(HashMap<Coord, u8>, PipeLoop)> {
+        let (mut map, start) = Self::parse(input)?;
+        Self::infer(&mut map, &start)?;
+        match Self::follow(&map, start) {
+            (cells, true) => Ok((map, PipeLoop { cells })),
+            _ => Err(LoopError::StartNotOnLoop)?,
+        }
+    }
+
+    // The cells enclosed by the loop.  Scanning each row from the left, we are inside after
+    // crossing an odd number of loop parts connecting to the north.  Pick's theorem gives the
+    // number of them directly, as a cross-check.
+    fn enclosed(map: &HashMap<Coord, u8>, pipe_loop: &PipeLoop) -> HashSet<Coord> {
+        let on_loop = pipe_loop.cells.iter().copied().collect::<HashSet<_>>();
+        let (min_x, max_x) = on_loop.iter().map(|c| c.0).minmax().into_option().unwrap();
+        let (min_y, max_y) = on_loop.iter().map(|c| c.1).minmax().into_option().unwrap();
+        let enclosed = (min_y..=max_y)
+            .flat_map(|y| {
+                (min_x..=max_x).scan(false, {
+                    let on_loop = &on_loop;
+                    move |is_inside, x| {
                         let c = Coord(x, y);
-                        match map.get(&c).unwrap() /* XXX */ {
-                        b'|'  => {
-                            is_inside = !is_inside;
-                        }
-                        b'L' => {
-                            horizontal_from_north = Some(true);
-                        }
-                        b'F' => {
-                            horizontal_from_north = Some(false);
-                        }
-                        b'7' if horizontal_from_north == Some(true) => {
-                            is_inside = !is_inside;
-                            horizontal_from_north = None;
+                        if on_loop.contains(&c) {
+                            if Self::exits(Self::tile(map, c)).contains(&Dir::North) {
+                                *is_inside = !*is_inside;
+                            }
+                            Some(None)
+                        } else {
+                            Some(is_inside.then_some(c))
                         }
-                        b'J' if horizontal_from_north == Some(false) => {
-                            is_inside = !is_inside;
-                            horizontal_from_north = None;
-                        }
-                        b'.' => if is_inside {
-                            n += 1;
-                        }
-                        _ => (),
                     }
-                        (n, is_inside, horizontal_from_north)
-                    },
+                })
+            })
+            .flatten()
+            .collect::<HashSet<_>>();
+        debug_assert_eq!(enclosed.len() as isize, pipe_loop.polygon().interior());
+        enclosed
+    }
+
+    // Draw the loop with box-drawing characters, and mark the cells it encloses.
+    fn render(map: &HashMap<Coord, u8>, pipe_loop: &PipeLoop) -> String {
+        let on_loop = pipe_loop.cells.iter().copied().collect::<HashSet<_>>();
+        let enclosed = Self::enclosed(map, pipe_loop);
+        let width = map.keys().map(|c| c.0 + 1).max().unwrap_or(0);
+        let height = map.keys().map(|c| c.1 + 1).max().unwrap_or(0);
+        (0..height)
+            .map(|y| {
+                (0..width)
+                    .map(|x| {
+                        let c = Coord(x, y);
+                        if on_loop.contains(&c) {
+                            match Self::tile(map, c) {
+                                b'-' => '─',
+                                b'|' => '│',
+                                b'F' => '┌',
+                                b'7' => '┐',
+                                b'L' => '└',
+                                b'J' => '┘',
+                                _ => '?',
+                            }
+                        } else if enclosed.contains(&c) {
+                            'I'
+                        } else {
+                            ' '
+                        }
+                    })
+                    .collect::<String>()
+                    .trim_end()
+                    .to_string()
+            })
+            .join("\n")
+    }
+
+    fn report_impl(&self, input: &mut dyn io::Read) -> BoxResult<String> {
+        let (map, loops, i) = Self::survey(input)?;
+        Ok(loops
+            .iter()
+            .enumerate()
+            .map(|(j, pipe_loop)| {
+                let Coord(x, y) = pipe_loop.cells[0];
+                format!(
+                    "loop {} at ({}, {}): {} pipes{}",
+                    j + 1,
+                    x,
+                    y,
+                    pipe_loop.cells.len(),
+                    if j == i { ", through the start" } else { "" }
                 )
-                .0
-        }))
+            })
+            .chain([Self::render(&map, &loops[i])])
+            .join("\n"))
+    }
+
+    fn part1_impl(&self, input: &mut dyn io::Read) -> BoxResult<Output> {
+        let (_, pipe_loop) = Self::analyse(input)?;
+        Ok(pipe_loop.cells.len() / 2)
+    }
+
+    fn part2_impl(&self, input: &mut dyn io::Read) -> BoxResult<Output> {
+        let (map, pipe_loop) = Self::analyse(input)?;
+        Ok(Self::enclosed(&map, &pipe_loop).len())
     }
 }
 
@@ -305,4 +446,111 @@ L7JLJL-JLJLJL--JLJ.L",
             10,
         );
     }
+
+    #[test]
+    fn border() {
+        // The loop runs along the edges of the map, and the start is in a corner.
+        let s = "S-7
+|.|
+L-J";
+        test1(s, 4);
+        test2(s, 1);
+        test2("S7\nLJ", 0);
+        // A pipe leading off the map does not connect.
+        test2("7S7\n.||\n.LJ", 0);
+    }
+
+    #[test]
+    fn report() {
+        let (_, pipe_loop) = Day10::analyse(
+            &mut "..F7.
+.FJ|.
+SJ.L7
+|F--J
+LJ..."
+                .as_bytes(),
+        )
+        .unwrap();
+        assert_eq!(pipe_loop.cells.len(), 16);
+        assert_eq!(pipe_loop.cells[0], Coord(0, 2));
+        assert_eq!(pipe_loop.polygon().interior(), 1);
+        assert_eq!(
+            Day10 {}
+                .report_impl(
+                    &mut "...........
+.S-------7.
+.|F-----7|.
+.||.....||.
+.||.....||.
+.|L-7.F-J|.
+.|..|.|..|.
+.L--J.L--J.
+..........."
+                        .as_bytes()
+                )
+                .unwrap(),
+            "loop 1 at (1, 1): 46 pipes, through the start
+
+ ┌───────┐
+ │┌─────┐│
+ ││     ││
+ ││     ││
+ │└─┐ ┌─┘│
+ │II│ │II│
+ └──┘ └──┘
+"
+        );
+        assert!(Day10 {}.report_impl(&mut "S.\n..".as_bytes()).is_err());
+    }
+
+    #[test]
+    fn loops() {
+        let s = "F7..F-7
+LJ..|.|
+.S7.L-J
+.LJ-7..";
+        let (map, loops, i) = Day10::survey(&mut s.as_bytes()).unwrap();
+        assert_eq!(
+            loops
+                .iter()
+                .map(|l| (l.cells[0], l.cells.len()))
+                .collect_vec(),
+            [(Coord(0, 0), 4), (Coord(4, 0), 8), (Coord(1, 2), 4)]
+        );
+        assert_eq!(i, 2);
+        assert_eq!(Day10::enclosed(&map, &loops[1]).len(), 1);
+        test1(s, 2);
+        assert_eq!(
+            Day10 {}.report_impl(&mut s.as_bytes()).unwrap(),
+            "loop 1 at (0, 0): 4 pipes
+loop 2 at (4, 0): 8 pipes
+loop 3 at (1, 2): 4 pipes, through the start
+
+
+ ┌┐
+ └┘"
+        );
+    }
+
+    fn error(s: &str) -> String {
+        Day10 {}
+            .part1_impl(&mut s.as_bytes())
+            .unwrap_err()
+            .to_string()
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(error("F7\nLJ"), "the map has no start");
+        assert_eq!(error("S7\nLS"), "the map has more than one start");
+        assert_eq!(
+            error(".|.\n-S-\n.|."),
+            "the start connects to 4 neighbours: [East, South, West, North]"
+        );
+        assert_eq!(error("S-\n.."), "the start is not on a closed loop");
+        // Two neighbours connect, but they lead nowhere.
+        assert_eq!(error("S-7\n|..\nL-."), "the start is not on a closed loop");
+        // Nor is it when there are other loops.
+        assert!(Day10::survey(&mut "F7S-7\nLJ|.|\n..L-.".as_bytes()).is_err());
+    }
 }
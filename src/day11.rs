@@ -1,9 +1,9 @@
 use crate::day::*;
-use std::io::Read;
+use num::BigUint;
 
 pub struct Day11 {}
 
-type Output = usize;
+type Output = BigUint;
 
 impl Day for Day11 {
     fn tag(&self) -> &str {
@@ -15,19 +15,27 @@ impl Day for Day11 {
     }
 
     fn part2(&self, input: &dyn Fn() -> Box<dyn io::Read>) {
-        println!("{:?}", self.part2_impl(&mut *input(), 999999));
+        println!("{:?}", self.part2_impl(&mut *input(), 1000000));
     }
 }
 
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 struct Coord(usize, usize);
 
-impl Coord {
-    fn distance(self, other: Coord) -> Output {
-        (i64::abs(self.0 as i64 - other.0 as i64) + i64::abs(self.1 as i64 - other.1 as i64))
-            as Output
+// The sum of the distances between all pairs of galaxies when every empty row and column
+// counts as `factor` of them, which is constant + slope * factor.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+struct DistanceSum {
+    constant: u64,
+    slope: u64,
+}
+
+impl DistanceSum {
+    fn at(&self, factor: &BigUint) -> BigUint {
+        BigUint::from(self.constant) + BigUint::from(self.slope) * factor
     }
 }
+
 impl Day11 {
     fn parse(input: &mut dyn io::Read) -> BoxResult<Vec<Coord>> {
         io::BufReader::new(input)
@@ -46,50 +54,58 @@ impl Day11 {
             })
     }
 
-    fn expand(space: &mut [Coord], n: Output) -> BoxResult<()> {
-        Self::expand_axis(space, n, |&Coord(x, _)| x, |Coord(x, _)| x)?;
-        Self::expand_axis(space, n, |&Coord(_, y)| y, |Coord(_, y)| y)
-    }
-
-    fn expand_axis<F1, F2>(space: &mut [Coord], n: Output, axis: F1, axis_mut: F2) -> BoxResult<()>
-    where
-        F1: Fn(&Coord) -> Output,
-        F2: Fn(&mut Coord) -> &mut Output,
-    {
-        let min = space.iter().map(&axis).min().ok_or(AocError)?;
-        let max = space.iter().map(&axis).max().ok_or(AocError)?;
-        let empties = ((min + 1)..max)
-            .filter(|&i| !space.iter().any(|c| axis(c) == i))
-            .collect::<Vec<_>>();
-        for i in empties.into_iter().rev() {
-            space
-                .iter_mut()
-                .filter(|c| axis(c) > i)
-                .for_each(|c| *axis_mut(c) += n);
-        }
-        Ok(())
+    // The sum of the differences between all pairs of sorted values, adding up how much each
+    // exceeds the ones before it.
+    fn pairwise(sorted: impl Iterator<Item = u64>) -> u64 {
+        sorted
+            .enumerate()
+            .fold((0, 0), |(sum, prefix), (i, v)| {
+                (sum + v * i as u64 - prefix, prefix + v)
+            })
+            .0
     }
 
-    fn distance(space: &[Coord]) -> BoxResult<Output> {
-        Ok(space
+    // Split the distances along one axis into the occupied lines and the empty lines crossed.
+    // The occupied lines below a value are its rank among the distinct values, and the rest
+    // of the lines below it are empty.
+    fn axis(mut values: Vec<usize>) -> DistanceSum {
+        values.sort_unstable();
+        let lines = values
             .iter()
-            .combinations(2)
-            .map(|p| p[0].distance(*p[1]))
-            .sum())
+            .scan((0, None), |(rank, last), &v| {
+                if last.is_some_and(|last| last < v) {
+                    *rank += 1;
+                }
+                *last = Some(v);
+                Some((*rank as u64, (v - *rank) as u64))
+            })
+            .collect_vec();
+        DistanceSum {
+            constant: Self::pairwise(lines.iter().map(|&(rank, _)| rank)),
+            slope: Self::pairwise(lines.iter().map(|&(_, empty)| empty)),
+        }
+    }
+
+    fn distance_sum(space: &[Coord]) -> DistanceSum {
+        let x = Self::axis(space.iter().map(|c| c.0).collect());
+        let y = Self::axis(space.iter().map(|c| c.1).collect());
+        DistanceSum {
+            constant: x.constant + y.constant,
+            slope: x.slope + y.slope,
+        }
     }
 
-    fn process(input: &mut dyn Read, n: Output) -> BoxResult<Output> {
-        let mut space = Self::parse(input)?;
-        Self::expand(&mut space, n)?;
-        Self::distance(&space)
+    fn process(input: &mut dyn io::Read, factors: &[BigUint]) -> BoxResult<Vec<Output>> {
+        let sum = Self::distance_sum(&Self::parse(input)?);
+        Ok(factors.iter().map(|factor| sum.at(factor)).collect())
     }
 
     fn part1_impl(&self, input: &mut dyn io::Read) -> BoxResult<Output> {
-        Self::process(input, 1)
+        Ok(Self::process(input, &[2u32.into()])?.remove(0))
     }
 
-    fn part2_impl(&self, input: &mut dyn io::Read, n: Output) -> BoxResult<Output> {
-        Self::process(input, n)
+    fn part2_impl(&self, input: &mut dyn io::Read, factor: usize) -> BoxResult<Output> {
+        Ok(Self::process(input, &[factor.into()])?.remove(0))
     }
 }
 
@@ -97,14 +113,7 @@ impl Day11 {
 mod tests {
     use super::*;
 
-    fn test1(s: &str, f: Output) {
-        assert_eq!(Day11 {}.part1_impl(&mut s.as_bytes()).ok(), Some(f));
-    }
-
-    #[test]
-    fn part1() {
-        test1(
-            "...#......
+    const EXAMPLE: &str = "...#......
 .......#..
 #.........
 ..........
@@ -113,44 +122,73 @@ mod tests {
 .........#
 ..........
 .......#..
-#...#.....",
-            374,
-        );
+#...#.....";
+
+    fn test1(s: &str, f: usize) {
+        assert_eq!(Day11 {}.part1_impl(&mut s.as_bytes()).ok(), Some(f.into()));
     }
 
-    fn test2(s: &str, n: Output, f: Output) {
-        assert_eq!(Day11 {}.part2_impl(&mut s.as_bytes(), n).ok(), Some(f));
+    #[test]
+    fn part1() {
+        test1(EXAMPLE, 374);
+    }
+
+    fn test2(s: &str, factor: usize, f: usize) {
+        assert_eq!(
+            Day11 {}.part2_impl(&mut s.as_bytes(), factor).ok(),
+            Some(f.into())
+        );
     }
 
     #[test]
     fn part2() {
-        test2(
-            "...#......
-.......#..
-#.........
-..........
-......#...
-.#........
-.........#
-..........
-.......#..
-#...#.....",
-            9,
-            1030,
+        test2(EXAMPLE, 10, 1030);
+        test2(EXAMPLE, 100, 8410);
+    }
+
+    #[test]
+    fn factors() {
+        let space = Day11::parse(&mut EXAMPLE.as_bytes()).unwrap();
+        let sum = Day11::distance_sum(&space);
+        assert_eq!(
+            sum,
+            DistanceSum {
+                constant: 210,
+                slope: 82
+            }
         );
-        test2(
-            "...#......
-.......#..
-#.........
-..........
-......#...
-.#........
-.........#
-..........
-.......#..
-#...#.....",
-            99,
-            8410,
+        let factors = [0u32, 1, 2, 10, 100].map(BigUint::from);
+        assert_eq!(
+            Day11::process(&mut EXAMPLE.as_bytes(), &factors).unwrap(),
+            [210u32, 292, 374, 1030, 8410].map(BigUint::from)
         );
+        let huge = BigUint::from(10u32).pow(30);
+        assert_eq!(
+            sum.at(&huge).to_string(),
+            "82000000000000000000000000000210"
+        );
+    }
+
+    #[test]
+    fn large() {
+        // Compare with expanding the space and summing the distances of all pairs.
+        let space = (0..200)
+            .map(|i: usize| Coord(i * i % 97 * 3, i * 7 % 211))
+            .collect_vec();
+        let expand = |values: Vec<usize>, factor: usize| {
+            values
+                .iter()
+                .map(|&v| v + (0..v).filter(|u| !values.contains(u)).count() * (factor - 1))
+                .collect_vec()
+        };
+        for factor in [1, 2, 5] {
+            let xs = expand(space.iter().map(|c| c.0).collect(), factor);
+            let ys = expand(space.iter().map(|c| c.1).collect(), factor);
+            let naive = (0..space.len())
+                .tuple_combinations()
+                .map(|(i, j)| xs[i].abs_diff(xs[j]) + ys[i].abs_diff(ys[j]))
+                .sum::<usize>();
+            assert_eq!(Day11::distance_sum(&space).at(&factor.into()), naive.into());
+        }
     }
 }
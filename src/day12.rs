@@ -1,13 +1,11 @@
-use lazy_static::lazy_static;
-use regex::Regex;
-use std::collections::HashMap;
+use num::{BigUint, One, Zero};
 use std::iter;
 
 use crate::day::*;
 
 pub struct Day12 {}
 
-type Output = usize;
+type Output = BigUint;
 
 impl Day for Day12 {
     fn tag(&self) -> &str {
@@ -21,119 +19,223 @@ impl Day for Day12 {
     fn part2(&self, input: &dyn Fn() -> Box<dyn io::Read>) {
         println!("{:?}", self.part2_impl(&mut *input()));
     }
+
+    fn report(&self, input: &dyn Fn() -> Box<dyn io::Read>) {
+        match self.report_impl(&mut *input()) {
+            Ok(report) => println!("{}", report),
+            Err(e) => println!("{:?}", e),
+        }
+    }
 }
 
-lazy_static! {
-    static ref PATTERN: Regex = Regex::new("#+").unwrap();
+// A row of springs, operational '.', damaged '#' or unknown '?', and the lengths of its runs of
+// damaged springs in order: a line of a nonogram.
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct Line {
+    cells: Vec<u8>,
+    runs: Vec<usize>,
 }
 
-impl Day12 {
-    fn unfold(springs: &str, ranges: &str) -> (String, String) {
-        (
-            iter::repeat(springs).take(5).join("?"),
-            iter::repeat(ranges).take(5).join(","),
-        )
+impl Line {
+    fn parse(s: &str) -> BoxResult<Self> {
+        let mut tokens = s.split_whitespace();
+        let (cells, runs) = (
+            tokens.next().ok_or(AocError)?,
+            tokens.next().ok_or(AocError)?,
+        );
+        if !cells.bytes().all(|b| b == b'.' || b == b'#' || b == b'?') {
+            return Err(AocError.into());
+        }
+        let runs = runs
+            .split(',')
+            .map(|s| s.parse().map_err(|_| AocError))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Line {
+            cells: cells.bytes().collect(),
+            runs,
+        })
     }
 
-    // returns BoxResult<Vec<(is_running, is_range_done)>>
-    fn validate_single(c: u8, is_running: bool, len: usize) -> Vec<(bool, bool)> {
-        if c == b'?' { vec![b'.', b'#'] } else { vec![c] }
-            .into_iter()
-            .flat_map(|c| match c {
-                b'.' if !is_running => Some((false, false)),
-                b'.' if is_running && len == 0 => Some((false, true)),
-                b'#' if len > 0 => Some((true, false)),
-                b'.' | b'#' => None,
-                _ => unreachable!(), // XXX
-            })
-            .collect_vec()
+    // Repeat the line, with an unknown spring between the copies.
+    fn unfold(&self, factor: usize) -> Self {
+        Line {
+            cells: iter::repeat_n(&self.cells[..], factor)
+                .collect_vec()
+                .join(&b'?'),
+            runs: self.runs.repeat(factor),
+        }
     }
 
-    fn validate(springs: &[u8], ranges: &Vec<Output>) -> Output {
-        let mut states = HashMap::new();
-        states.insert((false, ranges.to_owned()), 1 as Output);
-        let rv = springs.iter().enumerate().fold(states, |states, (i, &b)| {
-            let min_damage = springs.iter().skip(i).filter(|&b| *b == b'#').count();
-            let max_damage = springs
-                .iter()
-                .skip(i)
-                .filter(|&b| *b == b'#' || *b == b'?')
-                .count();
-            let mut new_states = HashMap::new();
-            states.into_iter().for_each(|((is_running, ranges), cnt)| {
-                let damaged_left: Output = ranges.iter().copied().sum();
-                if damaged_left < min_damage || damaged_left > max_damage {
-                    //                                println!("PRUNE! {}", damaged_left);
-                } else {
-                    let v = Self::validate_single(
-                        b,
-                        is_running,
-                        if ranges.is_empty() { 0 } else { ranges[0] },
-                    );
-                    v.into_iter().for_each(|(is_running, is_range_complete)| {
-                        let state = (
-                            is_running,
-                            if is_range_complete {
-                                let mut ranges = ranges.clone();
-                                ranges.remove(0);
-                                ranges
-                            } else if is_running {
-                                let mut ranges = ranges.clone();
-                                ranges[0] -= 1;
-                                ranges
-                            } else {
-                                ranges.clone()
-                            },
-                        );
-                        *new_states.entry(state).or_insert(0) += cnt;
-                    });
+    // Where run j would end if it started at cell i, or None if it does not fit there: all its
+    // cells may be damaged, and the cell after it, if any, may be operational.
+    fn fits(&self, i: usize, j: usize) -> Option<usize> {
+        let end = i + self.runs[j];
+        (end <= self.cells.len()
+            && !self.cells[i..end].contains(&b'.')
+            && self.cells.get(end) != Some(&b'#'))
+        .then_some(end)
+    }
+
+    // The cell after a run ending at end, skipping the operational spring separating it from
+    // the next one.
+    fn next(&self, end: usize) -> usize {
+        (end + 1).min(self.cells.len())
+    }
+
+    // For every cell i and run j, combine the ways of placing runs j and on in cells i and on,
+    // so this counts them or just tells if there are any.
+    fn table<T: Clone>(&self, none: T, one: T, plus: impl Fn(&T, &T) -> T) -> Vec<Vec<T>> {
+        let (n, m) = (self.cells.len(), self.runs.len());
+        let mut table = vec![vec![none.clone(); m + 1]; n + 1];
+        table[n][m] = one;
+        for i in (0..n).rev() {
+            for j in 0..=m {
+                let mut ways = none.clone();
+                if self.cells[i] != b'#' {
+                    ways = plus(&ways, &table[i + 1][j]);
                 }
-            });
-            new_states
-        });
-        let v = rv
-            .iter()
-            .filter(|((_, v), _)| v.is_empty() || v.len() == 1 && v[0] == 0)
-            .map(|(_, &cnt)| cnt)
-            .collect_vec();
-        let rv = v.into_iter().sum();
-        rv
+                if let Some(end) = (j < m).then(|| self.fits(i, j)).flatten() {
+                    ways = plus(&ways, &table[self.next(end)][j + 1]);
+                }
+                table[i][j] = ways;
+            }
+        }
+        table
     }
 
-    fn process(input: &mut dyn io::Read, is_folded: bool) -> BoxResult<Output> {
-        Ok(io::BufReader::new(input)
-            .lines()
-            .map(|rs| {
-                rs.map_err(|_| AocError).and_then(|s| {
-                    let mut tokens = s.split_whitespace();
-                    let (springs, ranges) = (
-                        tokens.next().ok_or(AocError)?,
-                        tokens.next().ok_or(AocError)?,
-                    );
-                    let (mut springs, mut ranges) = (springs.to_string(), ranges.to_string());
-                    if is_folded {
-                        (springs, ranges) = Self::unfold(&springs, &ranges);
+    fn count(&self) -> Output {
+        self.table(Output::zero(), Output::one(), |a, b| a + b)[0][0].clone()
+    }
+
+    fn feasible(&self) -> Vec<Vec<bool>> {
+        self.table(false, true, |a, b| *a || *b)
+    }
+
+    // All the arrangements, operational springs first, produced as needed.  Only steps that
+    // can still be completed are taken, so each one comes after at most one pass over the line.
+    #[allow(unused)]
+    fn arrangements(&self) -> impl Iterator<Item = Vec<u8>> + '_ {
+        let feasible = self.feasible();
+        let (n, m) = (self.cells.len(), self.runs.len());
+        let mut stack = if feasible[0][0] {
+            vec![(0, 0, vec![])]
+        } else {
+            vec![]
+        };
+        iter::from_fn(move || {
+            while let Some((i, j, mut cells)) = stack.pop() {
+                if i == n {
+                    return Some(cells);
+                }
+                if let Some(end) = (j < m).then(|| self.fits(i, j)).flatten() {
+                    let next = self.next(end);
+                    if feasible[next][j + 1] {
+                        let mut cells = cells.clone();
+                        cells.resize(end, b'#');
+                        cells.resize(next, b'.');
+                        stack.push((next, j + 1, cells));
                     }
-                    let springs = springs.as_bytes();
-                    let ranges = ranges
-                        .split(',')
-                        .map(|s| Ok(s.parse()?))
-                        .collect::<BoxResult<Vec<Output>>>()
-                        .map_err(|_| AocError)?;
-                    Ok(Self::validate(springs, &ranges))
+                }
+                if self.cells[i] != b'#' && feasible[i + 1][j] {
+                    cells.push(b'.');
+                    stack.push((i + 1, j, cells));
+                }
+            }
+            None
+        })
+    }
+
+    // The springs that are damaged '#' or operational '.' in every arrangement, and '?' for the
+    // rest, or None if there is no arrangement.  Walk forward through the steps that can still
+    // be completed, noting what each cell can be.
+    fn forced(&self) -> Option<Vec<u8>> {
+        let feasible = self.feasible();
+        let (n, m) = (self.cells.len(), self.runs.len());
+        if !feasible[0][0] {
+            return None;
+        }
+        let mut reached = vec![vec![false; m + 1]; n + 1];
+        reached[0][0] = true;
+        let mut operational = vec![false; n];
+        // The number of runs that can cover each cell, as differences from the one before.
+        let mut damaged = vec![0isize; n + 1];
+        for i in 0..n {
+            for j in 0..=m {
+                if !reached[i][j] {
+                    continue;
+                }
+                if self.cells[i] != b'#' && feasible[i + 1][j] {
+                    operational[i] = true;
+                    reached[i + 1][j] = true;
+                }
+                if let Some(end) = (j < m).then(|| self.fits(i, j)).flatten() {
+                    let next = self.next(end);
+                    if feasible[next][j + 1] {
+                        damaged[i] += 1;
+                        damaged[end] -= 1;
+                        if end < n {
+                            operational[end] = true;
+                        }
+                        reached[next][j + 1] = true;
+                    }
+                }
+            }
+        }
+        Some(
+            damaged
+                .iter()
+                .scan(0, |covered, d| {
+                    *covered += d;
+                    Some(*covered > 0)
                 })
-            })
-            .collect::<Result<Vec<_>, AocError>>()?
-            .into_iter()
+                .zip(operational)
+                .map(|(damaged, operational)| match (damaged, operational) {
+                    (true, false) => b'#',
+                    (false, true) => b'.',
+                    _ => b'?',
+                })
+                .collect(),
+        )
+    }
+}
+
+impl Day12 {
+    fn parse(input: &mut dyn io::Read) -> BoxResult<Vec<Line>> {
+        io::BufReader::new(input)
+            .lines()
+            .map(|rs| Line::parse(&rs?))
+            .collect()
+    }
+
+    fn process(input: &mut dyn io::Read, factor: usize) -> BoxResult<Output> {
+        Ok(Self::parse(input)?
+            .iter()
+            .map(|line| line.unfold(factor).count())
             .sum())
     }
 
+    // Each line with what is known about its springs, and the number of arrangements.
+    fn report_impl(&self, input: &mut dyn io::Read) -> BoxResult<String> {
+        Ok(Self::parse(input)?
+            .iter()
+            .map(|line| {
+                let forced = line.forced().unwrap_or_else(|| line.cells.clone());
+                format!(
+                    "{} {} {}",
+                    String::from_utf8_lossy(&forced),
+                    line.runs.iter().join(","),
+                    line.count()
+                )
+            })
+            .join("\n"))
+    }
+
     fn part1_impl(&self, input: &mut dyn io::Read) -> BoxResult<Output> {
-        Self::process(input, false)
+        Self::process(input, 1)
     }
 
     fn part2_impl(&self, input: &mut dyn io::Read) -> BoxResult<Output> {
-        Self::process(input, true)
+        Self::process(input, 5)
     }
 }
 
@@ -141,8 +243,16 @@ impl Day12 {
 mod tests {
     use super::*;
 
-    fn test1(s: &str, f: Output) {
-        assert_eq!(Day12 {}.part1_impl(&mut s.as_bytes()).ok(), Some(f));
+    const EXAMPLE: &str = "???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5
+?###???????? 3,2,1
+";
+
+    fn test1(s: &str, f: usize) {
+        assert_eq!(Day12 {}.part1_impl(&mut s.as_bytes()).ok(), Some(f.into()));
     }
 
     #[test]
@@ -157,33 +267,106 @@ mod tests {
 ",
             6,
         );
-        test1(
-            "???.### 1,1,3
-.??..??...?##. 1,1,3
-?#?#?#?#?#?#?#? 1,3,1,6
-????.#...#... 4,1,1
-????.######..#####. 1,6,5
-?###???????? 3,2,1
-",
-            21,
-        );
+        test1(EXAMPLE, 21);
     }
 
-    fn test2(s: &str, f: Output) {
-        assert_eq!(Day12 {}.part2_impl(&mut s.as_bytes()).ok(), Some(f));
+    fn test2(s: &str, f: usize) {
+        assert_eq!(Day12 {}.part2_impl(&mut s.as_bytes()).ok(), Some(f.into()));
     }
 
     #[test]
     fn part2() {
-        test2(
-            "???.### 1,1,3
-.??..??...?##. 1,1,3
-?#?#?#?#?#?#?#? 1,3,1,6
-????.#...#... 4,1,1
-????.######..#####. 1,6,5
-?###???????? 3,2,1
-",
-            525152,
+        test2(EXAMPLE, 525152);
+    }
+
+    fn line(s: &str) -> Line {
+        Line::parse(s).unwrap()
+    }
+
+    #[test]
+    fn unfold() {
+        let counts = [1u32, 16384, 1, 16, 2500, 506250];
+        for (s, count) in EXAMPLE.lines().zip(counts) {
+            assert_eq!(line(s).unfold(5).count(), count.into());
+        }
+        assert_eq!(line(".# 1").unfold(3), line(".#?.#?.# 1,1,1"));
+        assert_eq!(line("? 1").unfold(0).count(), 1u32.into());
+        // Far more arrangements than fit in 64 bits.
+        assert_eq!(
+            line("?###???????? 3,2,1").unfold(20).count().to_string(),
+            "221683782005310058593750"
+        );
+    }
+
+    #[test]
+    fn arrangements() {
+        for s in EXAMPLE.lines() {
+            let line = line(s);
+            let arrangements = line.arrangements().collect_vec();
+            assert_eq!(BigUint::from(arrangements.len()), line.count());
+            assert!(arrangements.iter().all_unique());
+            // Each one agrees with the known springs and has the given runs.
+            for cells in arrangements {
+                assert_eq!(cells.len(), line.cells.len());
+                assert!(line
+                    .cells
+                    .iter()
+                    .zip(&cells)
+                    .all(|(a, b)| a == b || *a == b'?'));
+                let runs = cells
+                    .split(|&b| b == b'.')
+                    .map(|run| run.len())
+                    .filter(|&len| len > 0)
+                    .collect_vec();
+                assert_eq!(runs, line.runs);
+            }
+        }
+        assert_eq!(
+            line("?###???????? 3,2,1")
+                .arrangements()
+                .take(2)
+                .map(|cells| String::from_utf8(cells).unwrap())
+                .collect_vec(),
+            [".###....##.#", ".###...##..#"]
+        );
+        assert_eq!(line("#.# 2").arrangements().count(), 0);
+        // Lazily, so that the first few of a huge number come quickly.
+        assert_eq!(
+            line("?###???????? 3,2,1")
+                .unfold(20)
+                .arrangements()
+                .take(3)
+                .count(),
+            3
+        );
+    }
+
+    #[test]
+    fn forced() {
+        let forced = |s: &str| {
+            line(s)
+                .forced()
+                .map(|cells| String::from_utf8(cells).unwrap())
+        };
+        assert_eq!(forced("???.### 1,1,3").as_deref(), Some("#.#.###"));
+        assert_eq!(
+            forced("?###???????? 3,2,1").as_deref(),
+            Some(".###.???????")
+        );
+        assert_eq!(forced("???????? 2,1,2").as_deref(), Some("?#????#?"));
+        assert_eq!(forced("#.# 2"), None);
+        assert_eq!(
+            Day12 {}
+                .report_impl(&mut "???.### 1,1,3\n??? 1".as_bytes())
+                .unwrap(),
+            "#.#.### 1,1,3 1\n??? 1 3"
         );
     }
+
+    #[test]
+    fn errors() {
+        assert!(Line::parse("?x? 1").is_err());
+        assert!(Line::parse("??? 1,a").is_err());
+        assert!(Line::parse("???").is_err());
+    }
 }
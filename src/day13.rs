@@ -16,6 +16,48 @@ impl Day for Day13 {
     fn part2(&self, input: &dyn Fn() -> Box<dyn io::Read>) {
         println!("{:?}", self.part2_impl(&mut *input()));
     }
+
+    fn report(&self, input: &dyn Fn() -> Box<dyn io::Read>) {
+        match self.report_impl(&mut *input(), 1) {
+            Ok(report) => {
+                for (i, mirrors) in report.iter().enumerate() {
+                    for mirror in mirrors {
+                        println!(
+                            "{}: {:?} mirror after {}, smudges at {:?}",
+                            i, mirror.axis, mirror.position, mirror.smudges
+                        );
+                    }
+                }
+            }
+            Err(e) => println!("{:?}", e),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Axis {
+    // Between two rows.
+    Horizontal,
+    // Between two columns.
+    Vertical,
+}
+
+// A line of reflection, placed after the given number of rows or columns, and the (x, y)
+// positions on its near side of the smudges which have to be cleaned for it to reflect.
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct Mirror {
+    axis: Axis,
+    position: Output,
+    smudges: Vec<(Output, Output)>,
+}
+
+impl Mirror {
+    fn summary(&self) -> Output {
+        match self.axis {
+            Axis::Horizontal => 100 * self.position,
+            Axis::Vertical => self.position,
+        }
+    }
 }
 
 impl Day13 {
@@ -37,77 +79,77 @@ impl Day13 {
             .collect::<BoxResult<Vec<_>>>()
     }
 
-    fn find_mirror<F>(pattern: &Vec<Vec<u8>>, get_coord: &F, skip: Option<Output>) -> Output
+    // The cells before a mirror at y0 which differ from their reflection, but no more than
+    // limit + 1 of them, in pattern coordinates.
+    fn mismatches<F>(
+        pattern: &[Vec<u8>],
+        get_coord: &F,
+        y0: Output,
+        limit: usize,
+    ) -> Vec<(Output, Output)>
     where
         F: Fn((Output, Output)) -> (Output, Output),
     {
         let (x_max, y_max) = get_coord((pattern[0].len(), pattern.len()));
-        let rv = (1..y_max)
-            .position(|y0| {
-                skip.map_or(true, |skip| y0 != skip)
-                    && (0..y0).all(|y1| {
-                        let y2 = y0 + (y0 - y1) - 1;
-                        if y2 < y_max {
-                            (0..x_max)
-                                .map(|x| (get_coord((x, y1)), get_coord((x, y2))))
-                                .all(|((x1, y1), (x2, y2))| pattern[y1][x1] == pattern[y2][x2])
-                        } else {
-                            true
-                        }
-                    })
+        (y0.saturating_sub(y_max - y0)..y0)
+            .flat_map(|y1| {
+                let y2 = y0 + (y0 - y1) - 1;
+                (0..x_max).map(move |x| (get_coord((x, y1)), get_coord((x, y2))))
             })
-            .map(|y| y + 1)
-            .unwrap_or(0);
-        // unsafe {
-        //     for y in 0..pattern.len() {
-        //         println!("{}", String::from_utf8_unchecked(pattern[y].clone()))
-        //     }
-        //     println!("{}", rv);
-        // }
-        rv
-    }
-
-    fn find_mirror_2<F>(pattern: &Vec<Vec<u8>>, get_coord: &F, skip: Option<Output>) -> Output
-    where
-        F: Fn((Output, Output)) -> (Output, Output),
-    {
-        let (x_max, y_max) = (pattern[0].len(), pattern.len());
-        (0..y_max)
-            .flat_map(move |y| {
-                (0..x_max)
-                    .map(move |x| {
-                        let mut pattern = pattern.clone();
-                        pattern[y][x] = if pattern[y][x] == b'.' { b'#' } else { b'.' };
-                        Self::find_mirror(&pattern, get_coord, skip)
-                    })
-                    .find(|&n| n > 0)
+            .filter(|&((x1, y1), (x2, y2))| pattern[y1][x1] != pattern[y2][x2])
+            .map(|(coord, _)| coord)
+            .take(limit + 1)
+            .collect_vec()
+    }
+
+    // Every mirror with exactly the given number of smudges, horizontal ones first.
+    fn find_mirrors(pattern: &[Vec<u8>], smudges: usize) -> Vec<Mirror> {
+        [
+            (Axis::Horizontal, &(|c| c) as &dyn Fn(_) -> _),
+            (Axis::Vertical, &|(x, y)| (y, x)),
+        ]
+        .into_iter()
+        .flat_map(|(axis, get_coord)| {
+            let (_, y_max) = get_coord((pattern[0].len(), pattern.len()));
+            (1..y_max).flat_map(move |position| {
+                let cells = Self::mismatches(pattern, &get_coord, position, smudges);
+                (cells.len() == smudges).then_some(Mirror {
+                    axis,
+                    position,
+                    smudges: cells,
+                })
             })
-            .find(|&n| n > 0)
-            .unwrap_or(0)
+        })
+        .collect_vec()
     }
 
-    fn part1_impl(&self, input: &mut dyn io::Read) -> BoxResult<Output> {
+    fn summarize(&self, input: &mut dyn io::Read, smudges: usize) -> BoxResult<Output> {
         let patterns = Self::parse(input)?;
-        Ok(patterns
+        patterns
             .iter()
             .map(|pattern| {
-                100 * Self::find_mirror(pattern, &|c| c, None)
-                    + Self::find_mirror(pattern, &|(x, y)| (y, x), None)
+                Self::find_mirrors(pattern, smudges)
+                    .first()
+                    .map(Mirror::summary)
+                    .ok_or(AocError.into())
             })
-            .sum())
+            .sum()
     }
 
-    fn part2_impl(&self, input: &mut dyn io::Read) -> BoxResult<Output> {
+    fn report_impl(&self, input: &mut dyn io::Read, smudges: usize) -> BoxResult<Vec<Vec<Mirror>>> {
         let patterns = Self::parse(input)?;
         Ok(patterns
             .iter()
-            .map(|pattern| {
-                let a = Self::find_mirror(pattern, &|c| c, None);
-                let b = Self::find_mirror(pattern, &|(x, y)| (y, x), None);
-                100 * Self::find_mirror_2(pattern, &|c| c, Some(a))
-                    + Self::find_mirror_2(pattern, &|(x, y)| (y, x), Some(b))
-            })
-            .sum())
+            .map(|pattern| Self::find_mirrors(pattern, smudges))
+            .collect_vec())
+    }
+
+    fn part1_impl(&self, input: &mut dyn io::Read) -> BoxResult<Output> {
+        self.summarize(input, 0)
+    }
+
+    fn part2_impl(&self, input: &mut dyn io::Read) -> BoxResult<Output> {
+        self.summarize(input, 1)
     }
 }
 
@@ -119,10 +161,7 @@ mod tests {
         assert_eq!(Day13 {}.part1_impl(&mut s.as_bytes()).ok(), Some(f));
     }
 
-    #[test]
-    fn part1() {
-        test1(
-            "#.##..##.
+    const EXAMPLE: &str = "#.##..##.
 ..#.##.#.
 ##......#
 ##......#
@@ -136,9 +175,11 @@ mod tests {
 #####.##.
 #####.##.
 ..##..###
-#....#..#",
-            405,
-        );
+#....#..#";
+
+    #[test]
+    fn part1() {
+        test1(EXAMPLE, 405);
     }
 
     fn test2(s: &str, f: Output) {
@@ -147,23 +188,50 @@ mod tests {
 
     #[test]
     fn part2() {
-        test2(
-            "#.##..##.
-..#.##.#.
-##......#
-##......#
-..#.##.#.
-..##..##.
-#.#.##.#.
+        test2(EXAMPLE, 400);
+    }
 
-#...##..#
-#....#..#
-..##..###
-#####.##.
-#####.##.
-..##..###
-#....#..#",
-            400,
+    #[test]
+    fn report() {
+        assert_eq!(
+            Day13 {}.report_impl(&mut EXAMPLE.as_bytes(), 0).unwrap(),
+            vec![
+                vec![Mirror {
+                    axis: Axis::Vertical,
+                    position: 5,
+                    smudges: vec![],
+                }],
+                vec![Mirror {
+                    axis: Axis::Horizontal,
+                    position: 4,
+                    smudges: vec![],
+                }]
+            ]
+        );
+        assert_eq!(
+            Day13 {}.report_impl(&mut EXAMPLE.as_bytes(), 1).unwrap(),
+            vec![
+                vec![Mirror {
+                    axis: Axis::Horizontal,
+                    position: 3,
+                    smudges: vec![(0, 0)],
+                }],
+                vec![Mirror {
+                    axis: Axis::Horizontal,
+                    position: 1,
+                    smudges: vec![(4, 0)],
+                }]
+            ]
+        );
+        // With two smudges, the first pattern reflects after its first column.
+        let report = Day13 {}.report_impl(&mut EXAMPLE.as_bytes(), 2).unwrap();
+        assert_eq!(
+            report[0],
+            vec![Mirror {
+                axis: Axis::Vertical,
+                position: 1,
+                smudges: vec![(0, 0), (0, 6)],
+            }]
         );
     }
 }
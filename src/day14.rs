@@ -1,7 +1,5 @@
+use crate::cycle;
 use crate::day::*;
-use std::cmp::Ordering;
-use std::collections::HashMap;
-use std::hash::{Hash, Hasher};
 
 pub struct Day14 {}
 
@@ -32,105 +30,110 @@ enum Dir {
     North,
 }
 
-#[derive(Clone, Eq, PartialEq)]
-struct Dish(HashMap<Coord, u8>);
-
-impl Hash for Dish {
-    fn hash<H: Hasher>(&self, state: &mut H) {
-        let mut v = self.0.iter().filter(|&(_, &b)| b == b'O').collect_vec();
-        v.sort_by(|&(&a, _), &(&b, _)| {
-            let x = a.0.cmp(&b.0);
-            if x == Ordering::Equal {
-                a.1.cmp(&b.1)
-            } else {
-                x
-            }
-        });
-        v.hash(state)
+// A row of cells as a bitmask, 64 cells to a word.
+type Row = Vec<u64>;
+
+// The bits of a word within the cell range [a, b) of a row.
+fn mask(word: usize, a: usize, b: usize) -> u64 {
+    let (lo, hi) = (
+        a.max(word * 64) - word * 64,
+        b.min(word * 64 + 64) - word * 64,
+    );
+    if lo >= hi {
+        0
+    } else {
+        (u64::MAX >> (64 - (hi - lo))) << lo
     }
 }
 
-impl Dish {
-    #[allow(unused)]
-    fn print(&self, size: Coord) {
-        for y in 0..size.1 {
-            let s = (0..size.0).fold(String::new(), |mut s, x| {
-                s.push_str(&match self.0.get(&Coord(x, y)) {
-                    None => String::new(),
-                    Some(b) => String::from(*b as char),
-                });
-                s
-            });
-            println!("{}", s);
+fn count(row: &Row, a: usize, b: usize) -> usize {
+    (a / 64..b.div_ceil(64))
+        .map(|word| (row[word] & mask(word, a, b)).count_ones() as usize)
+        .sum()
+}
+
+fn fill(row: &mut Row, a: usize, b: usize, is_set: bool) {
+    let words = a / 64..b.div_ceil(64);
+    for (bits, word) in row[words.clone()].iter_mut().zip(words) {
+        if is_set {
+            *bits |= mask(word, a, b);
+        } else {
+            *bits &= !mask(word, a, b);
         }
     }
 }
-impl Day14 {
-    fn parse(input: &mut dyn io::Read) -> BoxResult<(Dish, Coord)> {
-        io::BufReader::new(input)
-            .lines()
-            .enumerate()
-            .try_fold(
-                (HashMap::new(), Coord(0, 0)),
-                |(mut map, mut size), (y, rs)| {
-                    if let Ok(x) = rs.as_ref().map(|s| s.len()) {
-                        if x > size.0 {
-                            size.0 = x
+
+fn get(row: &Row, x: usize) -> bool {
+    row[x / 64] & 1 << (x % 64) != 0
+}
+
+// The rounded rocks on the platform, row by row.  This is all that changes when tilting, so
+// it is cheap to compare and hash.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+struct Dish(Vec<Row>);
+
+// The fixed parts of the platform: its size, the cube-shaped rocks, and the runs of cells
+// between those on every row, which rounded rocks roll along when tilting east or west.
+struct Platform {
+    size: Coord,
+    cubes: Vec<Row>,
+    runs: Vec<Vec<(usize, usize)>>,
+}
+
+impl Platform {
+    fn new(size: Coord, cubes: Vec<Row>) -> Self {
+        let runs = cubes
+            .iter()
+            .map(|row| {
+                (0..=size.0)
+                    .filter(|&x| x == size.0 || get(row, x))
+                    .scan(0, |a, b| {
+                        let run = (*a, b);
+                        *a = b + 1;
+                        Some(run)
+                    })
+                    .filter(|(a, b)| a < b)
+                    .collect_vec()
+            })
+            .collect_vec();
+        Platform { size, cubes, runs }
+    }
+
+    fn tilt(&self, dish: &mut Dish, dir: Dir) {
+        let rows = &mut dish.0;
+        match dir {
+            // Settle the rows one by one from the edge, letting a whole row of rocks roll one
+            // cell at a time as long as any of them can.
+            Dir::North | Dir::South => {
+                let order = if dir == Dir::North {
+                    (0..self.size.1).collect_vec()
+                } else {
+                    (0..self.size.1).rev().collect_vec()
+                };
+                for (i, &y) in order.iter().enumerate().skip(1) {
+                    for word in 0..rows[y].len() {
+                        let mut moving = rows[y][word];
+                        for (&from, &to) in order[..=i].iter().rev().tuple_windows() {
+                            moving &= !rows[to][word] & !self.cubes[to][word];
+                            if moving == 0 {
+                                break;
+                            }
+                            rows[to][word] |= moving;
+                            rows[from][word] &= !moving;
                         }
                     }
-                    let _ = rs.map_err(|_| AocError).map(|s| {
-                        s.bytes().enumerate().for_each(|(x, b)| {
-                            map.insert(Coord(x, y), b);
-                        })
-                    });
-                    size.1 = y + 1;
-                    Ok((map, size))
-                },
-            )
-            .map(|(map, size)| (Dish(map), size))
-    }
-
-    fn tilt(dish: &mut Dish, size: Coord, dir: Dir) {
-        let (outer, inner, make_coord): (Vec<_>, Vec<_>, Box<dyn Fn(Output, Output) -> Coord>) =
-            match dir {
-                Dir::East => (
-                    (0..size.1).rev().collect_vec(),
-                    (0..size.0).collect_vec(),
-                    Box::new(|i, o| Coord(o, i)),
-                ),
-                Dir::South => (
-                    (0..size.0).rev().collect_vec(),
-                    (0..size.0).collect_vec(),
-                    Box::new(Coord),
-                ),
-                Dir::West => (
-                    (0..size.1).collect_vec(),
-                    (0..size.0).collect_vec(),
-                    Box::new(|i, o| Coord(o, i)),
-                ),
-                Dir::North => (
-                    (0..size.0).collect_vec(),
-                    (0..size.1).collect_vec(),
-                    Box::new(Coord),
-                ),
-            };
-        for o in &outer {
-            for i in &inner {
-                let (i, o) = (*i, *o);
-                if let Some(b'O') = dish.0.get(&make_coord(i, o)) {
-                    let mut v = outer.iter().copied().take_while(|&n| n != o).collect_vec();
-                    v.reverse();
-                    let o1 = v
-                        .into_iter()
-                        .position(|o| dish.0.get(&make_coord(i, o)).map_or(false, |&b| b != b'.'))
-                        .map_or(outer[0], |p| match dir {
-                            Dir::North | Dir::West => o - p,
-                            Dir::South | Dir::East => o + p,
-                        });
-                    if o != o1 {
-                        if let Some(b) = dish.0.remove(&make_coord(i, o)) {
-                            dish.0.insert(make_coord(i, o), b'.');
-                            dish.0.insert(make_coord(i, o1), b);
+                }
+            }
+            // Gather the rocks of each run at its end.
+            Dir::West | Dir::East => {
+                for (row, runs) in rows.iter_mut().zip(&self.runs) {
+                    for &(a, b) in runs {
+                        let n = count(row, a, b);
+                        fill(row, a, b, false);
+                        if dir == Dir::West {
+                            fill(row, a, a + n, true);
+                        } else {
+                            fill(row, b - n, b, true);
                         }
                     }
                 }
@@ -138,42 +141,117 @@ impl Day14 {
         }
     }
 
-    fn load(dish: &Dish, size: Coord, _dir: Dir) -> Output {
-        // XXX assumes dir is North
+    // Run a sequence of tilts a number of times, skipping ahead once the dish repeats.
+    fn run(&self, dish: Dish, program: &[Dir], times: usize) -> Dish {
+        cycle::nth_state(
+            dish,
+            |dish| {
+                let mut dish = dish.clone();
+                for &dir in program {
+                    self.tilt(&mut dish, dir);
+                }
+                dish
+            },
+            times,
+        )
+    }
+
+    // The load on the support beams along an edge, each rock counting its distance from the
+    // opposite edge.
+    fn load(&self, dish: &Dish, dir: Dir) -> Output {
+        let Coord(width, height) = self.size;
         dish.0
             .iter()
-            .filter(|&(_, &b)| b == b'O')
-            .map(|(Coord(_, y), _)| size.1 - *y)
+            .enumerate()
+            .map(|(y, row)| match dir {
+                Dir::North => (height - y) * count(row, 0, width),
+                Dir::South => (y + 1) * count(row, 0, width),
+                Dir::West => (0..width).filter(|&x| get(row, x)).map(|x| width - x).sum(),
+                Dir::East => (0..width).filter(|&x| get(row, x)).map(|x| x + 1).sum(),
+            })
             .sum()
     }
 
+    fn render(&self, dish: &Dish) -> String {
+        dish.0
+            .iter()
+            .zip(&self.cubes)
+            .map(|(rocks, cubes)| {
+                (0..self.size.0)
+                    .map(|x| match (get(rocks, x), get(cubes, x)) {
+                        (true, _) => 'O',
+                        (_, true) => '#',
+                        _ => '.',
+                    })
+                    .collect::<String>()
+            })
+            .join("\n")
+    }
+
+    #[allow(unused)]
+    fn print(&self, dish: &Dish) {
+        println!("{}", self.render(dish));
+    }
+}
+
+impl Day14 {
+    // A sequence of tilts, e.g. "NWSE" for a spin cycle.
+    fn program(s: &str) -> BoxResult<Vec<Dir>> {
+        s.chars()
+            .map(|c| match c {
+                'N' => Ok(Dir::North),
+                'W' => Ok(Dir::West),
+                'S' => Ok(Dir::South),
+                'E' => Ok(Dir::East),
+                _ => Err(AocError.into()),
+            })
+            .collect()
+    }
+
+    // The load toward an edge after running a tilt program a number of times.
+    fn spin_impl(
+        &self,
+        input: &mut dyn io::Read,
+        program: &str,
+        times: usize,
+        edge: Dir,
+    ) -> BoxResult<Output> {
+        let program = Self::program(program)?;
+        let (dish, platform) = Self::parse(input)?;
+        let dish = platform.run(dish, &program, times);
+        Ok(platform.load(&dish, edge))
+    }
+
+    fn parse(input: &mut dyn io::Read) -> BoxResult<(Dish, Platform)> {
+        let lines = io::BufReader::new(input)
+            .lines()
+            .collect::<Result<Vec<_>, _>>()?;
+        let size = Coord(
+            lines.iter().map(|s| s.len()).max().unwrap_or(0),
+            lines.len(),
+        );
+        let words = size.0.div_ceil(64);
+        let mut rocks = vec![vec![0; words]; size.1];
+        let mut cubes = vec![vec![0; words]; size.1];
+        for (y, s) in lines.iter().enumerate() {
+            for (x, b) in s.bytes().enumerate() {
+                match b {
+                    b'O' => fill(&mut rocks[y], x, x + 1, true),
+                    b'#' => fill(&mut cubes[y], x, x + 1, true),
+                    b'.' => {}
+                    _ => Err(AocError)?,
+                }
+            }
+        }
+        Ok((Dish(rocks), Platform::new(size, cubes)))
+    }
+
     fn part1_impl(&self, input: &mut dyn io::Read) -> BoxResult<Output> {
-        let (mut dish, size) = Self::parse(input)?;
-        Self::tilt(&mut dish, size, Dir::North);
-        Ok(Self::load(&dish, size, Dir::North))
+        self.spin_impl(input, "N", 1, Dir::North)
     }
 
     fn part2_impl(&self, input: &mut dyn io::Read) -> BoxResult<Output> {
-        let (mut dish, size) = Self::parse(input)?;
-        let mut seen = HashMap::new();
-        let mut history = Vec::new();
-        let _ = (0u64..).try_fold(1000000000u64, |left, i| {
-            seen.insert(dish.clone(), i);
-            history.push(dish.clone());
-            for dir in [Dir::North, Dir::West, Dir::South, Dir::East] {
-                Self::tilt(&mut dish, size, dir);
-            }
-            if let Some(&j) = seen.get(&dish) {
-                dish = history
-                    .get((j + (left - 1) % (i + 1 - j)) as usize)
-                    .unwrap()
-                    .clone();
-                Err(())
-            } else {
-                Ok(left - 1)
-            }
-        });
-        Ok(Self::load(&dish, size, Dir::North))
+        self.spin_impl(input, "NWSE", 1000000000, Dir::North)
     }
 }
 
@@ -185,10 +263,7 @@ mod tests {
         assert_eq!(Day14 {}.part1_impl(&mut s.as_bytes()).ok(), Some(f));
     }
 
-    #[test]
-    fn part1() {
-        test1(
-            "O....#....
+    const EXAMPLE: &str = "O....#....
 O.OO#....#
 .....##...
 OO.#O....O
@@ -197,9 +272,11 @@ O.#..O.#.#
 ..O..#O..O
 .......O..
 #....###..
-#OO..#....",
-            136,
-        );
+#OO..#....";
+
+    #[test]
+    fn part1() {
+        test1(EXAMPLE, 136);
     }
 
     fn test2(s: &str, f: Output) {
@@ -208,18 +285,122 @@ O.#..O.#.#
 
     #[test]
     fn part2() {
-        test2(
-            "O....#....
-O.OO#....#
+        test2(EXAMPLE, 64);
+    }
+
+    #[test]
+    fn spin_cycles() {
+        let (dish, platform) = Day14::parse(&mut EXAMPLE.as_bytes()).unwrap();
+        let program = Day14::program("NWSE").unwrap();
+        let dish = platform.run(dish, &program, 1);
+        assert_eq!(
+            platform.render(&dish),
+            ".....#....
+....#...O#
+...OO##...
+.OO#......
+.....OOO#.
+.O#...O#.#
+....O#....
+......OOOO
+#...O###..
+#..OO#...."
+        );
+        let dish = platform.run(dish, &program, 2);
+        assert_eq!(
+            platform.render(&dish),
+            ".....#....
+....#...O#
 .....##...
-OO.#O....O
-.O.....O#.
-O.#..O.#.#
-..O..#O..O
-.......O..
-#....###..
-#OO..#....",
-            64,
+..O#......
+.....OOO#.
+.O#...O#.#
+....O#...O
+.......OOO
+#...O###.O
+#.OOO#...O"
+        );
+    }
+
+    #[test]
+    fn programs() {
+        let (dish, platform) = Day14::parse(&mut EXAMPLE.as_bytes()).unwrap();
+        // Rocks in the same place count the same toward opposite edges as the dish is long.
+        let count = platform.load(&dish, Dir::East) + platform.load(&dish, Dir::West);
+        assert_eq!(count, 18 * 11);
+        assert_eq!(
+            platform.load(&dish, Dir::North) + platform.load(&dish, Dir::South),
+            count
+        );
+        let tilt = |program, edge| {
+            Day14 {}
+                .spin_impl(&mut EXAMPLE.as_bytes(), program, 1, edge)
+                .unwrap()
+        };
+        assert_eq!(tilt("N", Dir::North), 136);
+        assert_eq!(tilt("", Dir::North), 104);
+        // Tilting is idempotent, and only the last of opposite tilts counts.
+        assert_eq!(tilt("WW", Dir::West), tilt("W", Dir::West));
+        assert_eq!(tilt("NS", Dir::East), tilt("S", Dir::East));
+        assert_eq!(
+            Day14 {}
+                .spin_impl(&mut EXAMPLE.as_bytes(), "NWSE", 1000000000, Dir::North)
+                .ok(),
+            Some(64)
         );
+        assert!(Day14::program("NWX").is_err());
+    }
+
+    // Tilt cell by cell, letting rocks roll one step at a time until none can.
+    fn naive_tilt(map: &mut [Vec<u8>], dir: Dir) {
+        let (dx, dy) = match dir {
+            Dir::East => (1, 0),
+            Dir::South => (0, 1),
+            Dir::West => (-1, 0),
+            Dir::North => (0, -1),
+        };
+        let (w, h) = (map[0].len() as isize, map.len() as isize);
+        let mut is_moving = true;
+        while is_moving {
+            is_moving = false;
+            for (x, y) in (0..w).cartesian_product(0..h) {
+                let (x1, y1) = (x + dx, y + dy);
+                if (0..w).contains(&x1)
+                    && (0..h).contains(&y1)
+                    && map[y as usize][x as usize] == b'O'
+                    && map[y1 as usize][x1 as usize] == b'.'
+                {
+                    map[y as usize][x as usize] = b'.';
+                    map[y1 as usize][x1 as usize] = b'O';
+                    is_moving = true;
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn large() {
+        // A pseudo-random dish wider than a word.
+        let s = Utils::random_grid(7, 150, 70, "##OOO.....");
+        let (mut dish, platform) = Day14::parse(&mut s.as_bytes()).unwrap();
+        let mut map = s.lines().map(|s| s.bytes().collect_vec()).collect_vec();
+        for dir in [
+            Dir::North,
+            Dir::West,
+            Dir::South,
+            Dir::East,
+            Dir::West,
+            Dir::North,
+        ] {
+            platform.tilt(&mut dish, dir);
+            naive_tilt(&mut map, dir);
+            assert_eq!(
+                platform.render(&dish),
+                map.iter()
+                    .map(|row| String::from_utf8_lossy(row))
+                    .join("\n")
+            );
+        }
+        assert!(Day14 {}.part2_impl(&mut s.as_bytes()).is_ok());
     }
 }
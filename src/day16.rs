@@ -1,4 +1,6 @@
 use crate::day::*;
+use petgraph::algo::tarjan_scc;
+use petgraph::graphmap::DiGraphMap;
 use regex::Regex;
 use std::collections::{HashMap, HashSet};
 
@@ -18,6 +20,13 @@ impl Day for Day16 {
     fn part2(&self, input: &dyn Fn() -> Box<dyn io::Read>) {
         println!("{:?}", self.part2_impl(&mut *input()));
     }
+
+    fn report(&self, input: &dyn Fn() -> Box<dyn io::Read>) {
+        match self.report_impl(&mut *input()) {
+            Ok(report) => print!("{}", report),
+            Err(e) => println!("{:?}", e),
+        }
+    }
 }
 
 lazy_static! {
@@ -63,6 +72,179 @@ impl Coord {
     }
 }
 
+impl Dir {
+    // The directions a beam continues in after entering a tile.
+    fn turn(self, tile: Option<u8>) -> Vec<Dir> {
+        match (tile, self) {
+            (Some(b'-'), Dir::South | Dir::North) => vec![Dir::East, Dir::West],
+            (Some(b'|'), Dir::East | Dir::West) => vec![Dir::South, Dir::North],
+            (Some(b'/'), Dir::South) => vec![Dir::West],
+            (Some(b'/'), Dir::North) => vec![Dir::East],
+            (Some(b'/'), Dir::East) => vec![Dir::North],
+            (Some(b'/'), Dir::West) => vec![Dir::South],
+            (Some(b'\\'), Dir::South) => vec![Dir::East],
+            (Some(b'\\'), Dir::North) => vec![Dir::West],
+            (Some(b'\\'), Dir::East) => vec![Dir::South],
+            (Some(b'\\'), Dir::West) => vec![Dir::North],
+            _ => vec![self],
+        }
+    }
+}
+
+// A set of cells, as a bitmap over the rows of the contraption.
+#[derive(Clone, Debug)]
+struct Cells {
+    width: usize,
+    bits: Vec<u64>,
+}
+
+impl Cells {
+    fn new(size: Coord) -> Self {
+        Cells {
+            width: size.0,
+            bits: vec![0; (size.0 * size.1).div_ceil(64)],
+        }
+    }
+
+    fn insert(&mut self, coord: Coord) {
+        let i = coord.1 * self.width + coord.0;
+        self.bits[i / 64] |= 1 << (i % 64);
+    }
+
+    fn union(&mut self, other: &Cells) {
+        for (bits, other) in self.bits.iter_mut().zip(&other.bits) {
+            *bits |= other;
+        }
+    }
+
+    fn len(&self) -> Output {
+        self.bits
+            .iter()
+            .map(|bits| bits.count_ones() as Output)
+            .sum()
+    }
+
+    fn iter(&self) -> impl Iterator<Item = Coord> + '_ {
+        (0..self.bits.len() * 64)
+            .filter(|i| self.bits[i / 64] & 1 << (i % 64) != 0)
+            .map(|i| Coord(i % self.width, i / self.width))
+    }
+}
+
+// The contraption compiled into a graph of the splitters which split a beam, each with the
+// cells lit by it and by all splitters downstream of it.  Splitters lighting each other form
+// strongly connected components, which light the same cells.
+struct Contraption {
+    tiles: HashMap<Coord, u8>,
+    size: Coord,
+    splitters: HashMap<Coord, usize>,
+    lit: Vec<Cells>,
+}
+
+impl Contraption {
+    fn new(tiles: HashMap<Coord, u8>, size: Coord) -> Self {
+        let splitters = tiles
+            .iter()
+            .filter(|(_, &b)| b == b'-' || b == b'|')
+            .map(|(&coord, _)| coord)
+            .sorted_by_key(|coord| (coord.1, coord.0))
+            .enumerate()
+            .map(|(i, coord)| (coord, i))
+            .collect::<HashMap<_, _>>();
+        let mut contraption = Contraption {
+            tiles,
+            size,
+            splitters,
+            lit: vec![],
+        };
+        // The cells of the two beams leaving each splitter, up to the next splitters.
+        let mut segments = vec![Cells::new(size); contraption.splitters.len()];
+        let mut graph = DiGraphMap::new();
+        for (&coord, &i) in &contraption.splitters {
+            graph.add_node(i);
+            segments[i].insert(coord);
+            let dirs = if contraption.tiles[&coord] == b'-' {
+                [Dir::East, Dir::West]
+            } else {
+                [Dir::South, Dir::North]
+            };
+            for dir in dirs {
+                if let Some(next) = coord.walk(dir, size) {
+                    let (cells, splitter) = contraption.trace(next, dir);
+                    segments[i].union(&cells);
+                    if let Some(j) = splitter {
+                        graph.add_edge(i, j, ());
+                    }
+                }
+            }
+        }
+        // The components come downstream first.
+        contraption.lit = segments.clone();
+        for component in tarjan_scc(&graph) {
+            let mut lit = Cells::new(size);
+            for &i in &component {
+                lit.union(&segments[i]);
+                for j in graph.neighbors(i).filter(|j| !component.contains(j)) {
+                    lit.union(&contraption.lit[j]);
+                }
+            }
+            for &i in &component {
+                contraption.lit[i] = lit.clone();
+            }
+        }
+        contraption
+    }
+
+    // Follow a beam until it leaves the contraption or is split, returning the cells it lights
+    // and the splitter splitting it.
+    fn trace(&self, mut coord: Coord, mut dir: Dir) -> (Cells, Option<usize>) {
+        let mut cells = Cells::new(self.size);
+        let mut seen = HashSet::new();
+        while seen.insert((coord, dir)) {
+            cells.insert(coord);
+            let tile = self.tiles.get(&coord).copied();
+            match dir.turn(tile)[..] {
+                [dir_] => match coord.walk(dir_, self.size) {
+                    Some(next) => (coord, dir) = (next, dir_),
+                    None => break,
+                },
+                _ => return (cells, self.splitters.get(&coord).copied()),
+            }
+        }
+        (cells, None)
+    }
+
+    fn energized_cells(&self, coord: Coord, dir: Dir) -> Cells {
+        let (mut cells, splitter) = self.trace(coord, dir);
+        if let Some(i) = splitter {
+            cells.union(&self.lit[i]);
+        }
+        cells
+    }
+
+    // The cells energized by a beam entering at the given cell, heading in the given direction.
+    fn energized(&self, coord: Coord, dir: Dir) -> HashSet<Coord> {
+        self.energized_cells(coord, dir).iter().collect()
+    }
+
+    // All the ways for a beam to enter the contraption from its edges.
+    fn entries(&self) -> Vec<(Coord, Dir)> {
+        let size = self.size;
+        let verticals = (0..size.0)
+            .flat_map(|x| {
+                vec![
+                    (Coord(x, 0), Dir::South),
+                    (Coord(x, size.1 - 1), Dir::North),
+                ]
+            })
+            .collect_vec();
+        let horizontals = (0..size.1)
+            .flat_map(|y| vec![(Coord(0, y), Dir::East), (Coord(size.0 - 1, y), Dir::West)])
+            .collect_vec();
+        verticals.into_iter().chain(horizontals).collect_vec()
+    }
+}
+
 impl Day16 {
     fn parse(input: &mut dyn io::Read) -> BoxResult<(HashMap<Coord, u8>, Coord)> {
         io::BufReader::new(input).lines().enumerate().try_fold(
@@ -84,70 +266,51 @@ impl Day16 {
         )
     }
 
-    fn energize(tiles: &HashMap<Coord, u8>, size: Coord, start: Coord, dir: Dir) -> Output {
-        let mut seen = HashSet::new();
-        let _ = (0..).try_fold(vec![(start, dir)], |beams, _| {
-            let beams = beams
-                .into_iter()
-                .filter(|key| {
-                    let rv = !seen.contains(key);
-                    seen.insert(*key);
-                    rv
-                })
-                .flat_map(|(coord, dir)| {
-                    match tiles.get(&coord) {
-                        Some(b'-') if dir == Dir::South || dir == Dir::North => {
-                            vec![Dir::East, Dir::West]
-                        }
-                        Some(b'|') if dir == Dir::East || dir == Dir::West => {
-                            vec![Dir::South, Dir::North]
-                        }
-                        Some(b'/') if dir == Dir::South => vec![Dir::West],
-                        Some(b'/') if dir == Dir::North => vec![Dir::East],
-                        Some(b'/') if dir == Dir::East => vec![Dir::North],
-                        Some(b'/') if dir == Dir::West => vec![Dir::South],
-                        Some(b'\\') if dir == Dir::South => vec![Dir::East],
-                        Some(b'\\') if dir == Dir::North => vec![Dir::West],
-                        Some(b'\\') if dir == Dir::East => vec![Dir::South],
-                        Some(b'\\') if dir == Dir::West => vec![Dir::North],
-                        _ => vec![dir],
-                    }
-                    .into_iter()
-                    .flat_map(move |dir| coord.walk(dir, size).map(|coord| (coord, dir)))
-                    .collect_vec()
+    // The best entry point, and a map of the cells it energizes.
+    fn report_impl(&self, input: &mut dyn io::Read) -> BoxResult<String> {
+        let (tiles, size) = Self::parse(input)?;
+        let contraption = Contraption::new(tiles, size);
+        let (coord, dir) = contraption
+            .entries()
+            .into_iter()
+            .max_by_key(|&(coord, dir)| contraption.energized_cells(coord, dir).len())
+            .ok_or(AocError)?;
+        let cells = contraption.energized(coord, dir);
+        Ok(format!(
+            "{:?} {:?}: {}\n{}",
+            coord,
+            dir,
+            cells.len(),
+            (0..size.1)
+                .map(|y| {
+                    (0..size.0)
+                        .map(|x| {
+                            if cells.contains(&Coord(x, y)) {
+                                '#'
+                            } else {
+                                '.'
+                            }
+                        })
+                        .collect::<String>()
+                        + "\n"
                 })
-                .collect_vec();
-            if beams.is_empty() {
-                Err(())
-            } else {
-                Ok(beams)
-            }
-        });
-        seen.iter().map(|(coord, _)| coord).unique().count()
+                .join("")
+        ))
     }
 
     fn part1_impl(&self, input: &mut dyn io::Read) -> BoxResult<Output> {
         let (tiles, size) = Self::parse(input)?;
-        Ok(Self::energize(&tiles, size, Coord(0, 0), Dir::East))
+        let contraption = Contraption::new(tiles, size);
+        Ok(contraption.energized_cells(Coord(0, 0), Dir::East).len())
     }
 
     fn part2_impl(&self, input: &mut dyn io::Read) -> BoxResult<Output> {
         let (tiles, size) = Self::parse(input)?;
-        let verticals = (0..size.0)
-            .flat_map(|x| {
-                vec![
-                    (Coord(x, 0), Dir::South),
-                    (Coord(x, size.1 - 1), Dir::North),
-                ]
-            })
-            .collect_vec();
-        let horizontals = (0..size.1)
-            .flat_map(|y| vec![(Coord(0, y), Dir::East), (Coord(size.0 - 1, y), Dir::West)])
-            .collect_vec();
-        verticals
+        let contraption = Contraption::new(tiles, size);
+        contraption
+            .entries()
             .into_iter()
-            .chain(horizontals)
-            .map(|(coord, dir)| Self::energize(&tiles, size, coord, dir))
+            .map(|(coord, dir)| contraption.energized_cells(coord, dir).len())
             .max()
             .ok_or(AocError.into())
     }
@@ -161,10 +324,7 @@ mod tests {
         assert_eq!(Day16 {}.part1_impl(&mut s.as_bytes()).ok(), Some(f));
     }
 
-    #[test]
-    fn part1() {
-        test1(
-            ".|...\\....
+    const EXAMPLE: &str = ".|...\\....
 |.-.\\.....
 .....|-...
 ........|.
@@ -173,9 +333,11 @@ mod tests {
 ..../.\\\\..
 .-.-/..|..
 .|....-|.\\
-..//.|....",
-            46,
-        );
+..//.|....";
+
+    #[test]
+    fn part1() {
+        test1(EXAMPLE, 46);
     }
 
     fn test2(s: &str, f: Output) {
@@ -184,18 +346,57 @@ mod tests {
 
     #[test]
     fn part2() {
-        test2(
-            ".|...\\....
-|.-.\\.....
-.....|-...
-........|.
-..........
-.........\\
-..../.\\\\..
-.-.-/..|..
-.|....-|.\\
-..//.|....",
-            51,
-        );
+        test2(EXAMPLE, 51);
+    }
+
+    // Walk every beam state, one at a time.
+    fn brute_force(tiles: &HashMap<Coord, u8>, size: Coord, start: (Coord, Dir)) -> HashSet<Coord> {
+        let mut seen = HashSet::new();
+        let mut beams = vec![start];
+        while let Some((coord, dir)) = beams.pop() {
+            if seen.insert((coord, dir)) {
+                for dir in dir.turn(tiles.get(&coord).copied()) {
+                    beams.extend(coord.walk(dir, size).map(|coord| (coord, dir)));
+                }
+            }
+        }
+        seen.into_iter().map(|(coord, _)| coord).collect()
+    }
+
+    #[test]
+    fn energized() {
+        let (tiles, size) = Day16::parse(&mut EXAMPLE.as_bytes()).unwrap();
+        let contraption = Contraption::new(tiles.clone(), size);
+        let cells = contraption.energized(Coord(3, 0), Dir::South);
+        assert_eq!(cells.len(), 51);
+        assert!(cells.contains(&Coord(3, 0)));
+        assert!(!cells.contains(&Coord(0, 9)));
+        for (coord, dir) in contraption.entries() {
+            assert_eq!(
+                contraption.energized(coord, dir),
+                brute_force(&tiles, size, (coord, dir))
+            );
+        }
+    }
+
+    #[test]
+    fn report() {
+        let report = Day16 {}.report_impl(&mut EXAMPLE.as_bytes()).unwrap();
+        assert!(report.starts_with("Coord(3, 0) South: 51\n"));
+        assert!(report.ends_with("########..\n.#######..\n.#...#.#..\n"));
+    }
+
+    #[test]
+    fn large() {
+        // A pseudo-random contraption with plenty of splitters looping into each other.
+        let s = Utils::random_grid(1, 40, 40, "-|/\\........");
+        let (tiles, size) = Day16::parse(&mut s.as_bytes()).unwrap();
+        let contraption = Contraption::new(tiles.clone(), size);
+        for (coord, dir) in contraption.entries() {
+            assert_eq!(
+                contraption.energized(coord, dir),
+                brute_force(&tiles, size, (coord, dir))
+            );
+        }
     }
 }
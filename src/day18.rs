@@ -1,6 +1,7 @@
 use crate::day::*;
+use crate::polygon::Polygon;
 use regex::Regex;
-use std::collections::{BTreeMap, HashMap};
+use std::collections::BTreeMap;
 
 pub struct Day18 {}
 
@@ -18,15 +19,19 @@ impl Day for Day18 {
     fn part2(&self, input: &dyn Fn() -> Box<dyn io::Read>) {
         println!("{:?}", self.part2_impl(&mut *input()));
     }
+
+    fn svg(&self, input: &dyn Fn() -> Box<dyn io::Read>) {
+        match self.svg_impl(&mut *input(), false) {
+            Ok(svg) => print!("{}", svg),
+            Err(e) => println!("{:?}", e),
+        }
+    }
 }
 
 lazy_static! {
     static ref PATTERN: Regex = Regex::new("^([UDLR]) (\\d*) \\(#([0-9a-f]{6})\\)$").unwrap();
 }
 
-#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
-struct Coord(isize, isize);
-
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 enum Dir {
     East,
@@ -35,198 +40,68 @@ enum Dir {
     North,
 }
 
-impl Coord {
-    // Compute the next coordinate in a direction.
-    fn walk(self, dir: Dir) -> Self {
-        Coord(
-            self.0
-                + match dir {
-                    Dir::East => 1,
-                    Dir::West => -1,
-                    _ => 0,
-                },
-            self.1
-                + match dir {
-                    Dir::South => 1,
-                    Dir::North => -1,
-                    _ => 0,
-                },
-        )
-    }
-}
-
 impl Day18 {
-    fn parse_naive(
-        input: &mut dyn io::Read,
-        use_colour: bool,
-    ) -> BoxResult<(HashMap<Coord, (Dir, Option<Dir>)>, Coord, Coord)> {
-        let (mut map, coord, from_dir, _) = io::BufReader::new(input).lines().try_fold(
-            (HashMap::new(), Coord(0, 0), None, None),
-            |(map, coord, from_dir, start), rs| {
-                let s = rs.map_err(|_| AocError)?;
-                let (_, [dir, len, colour]) = PATTERN.captures(&s).ok_or(AocError)?.extract();
-                let dir = if use_colour {
-                    match colour.chars().last() {
-                        Some('3') => Ok(Dir::North),
-                        Some('1') => Ok(Dir::South),
-                        Some('2') => Ok(Dir::West),
-                        Some('0') => Ok(Dir::East),
-                        _ => Err(AocError),
-                    }
-                } else {
-                    match dir {
-                        "U" => Ok(Dir::North),
-                        "D" => Ok(Dir::South),
-                        "L" => Ok(Dir::West),
-                        "R" => Ok(Dir::East),
-                        _ => Err(AocError),
-                    }
-                }?;
-                let len: usize = if use_colour {
-                    usize::from_str_radix(&colour[..(colour.len() - 1)], 16)?
-                } else {
-                    len.parse()?
-                };
-                BoxResult::Ok((0..len).fold(
-                    (map, coord, from_dir, start),
-                    |(mut map, coord, from_dir, start), _| {
-                        map.insert(coord, (dir, from_dir));
-                        let coord = coord.walk(dir);
-                        (map, coord, Some(dir), start.or(Some(coord)))
-                    },
-                ))
-            },
-        )?;
-        if coord != Coord(0, 0) {
-            Err(AocError)?;
-        }
-        let start_mut = map.get_mut(&Coord(0, 0)).ok_or(AocError)?;
-        start_mut.1 = from_dir;
-        if let (Some(min), Some(max)) =
-            map.iter()
-                .fold((None, None), |(min, max), (Coord(x, y), _)| {
-                    let min_x = min.map_or(*x, |Coord(min, _)| isize::min(min, *x));
-                    let max_x = max.map_or(*x, |Coord(max, _)| isize::max(max, *x));
-                    let min_y = min.map_or(*y, |Coord(_, min)| isize::min(min, *y));
-                    let max_y = max.map_or(*y, |Coord(_, max)| isize::max(max, *y));
-                    (Some(Coord(min_x, min_y)), Some(Coord(max_x, max_y)))
-                })
-        {
-            Ok((map, min, max))
+    fn parse_step(rs: io::Result<String>, use_colour: bool) -> BoxResult<(Dir, isize)> {
+        let s = rs.map_err(|_| AocError)?;
+        let (_, [dir, len, colour]) = PATTERN.captures(&s).ok_or(AocError)?.extract();
+        let dir = if use_colour {
+            match colour.chars().last() {
+                Some('3') => Ok(Dir::North),
+                Some('1') => Ok(Dir::South),
+                Some('2') => Ok(Dir::West),
+                Some('0') => Ok(Dir::East),
+                _ => Err(AocError),
+            }
         } else {
-            Err(AocError)?
-        }
+            match dir {
+                "U" => Ok(Dir::North),
+                "D" => Ok(Dir::South),
+                "L" => Ok(Dir::West),
+                "R" => Ok(Dir::East),
+                _ => Err(AocError),
+            }
+        }?;
+        let len = if use_colour {
+            isize::from_str_radix(&colour[..(colour.len() - 1)], 16)?
+        } else {
+            len.parse()?
+        };
+        Ok((dir, len))
     }
 
-    fn parse(
-        input: &mut dyn io::Read,
-        use_colour: bool,
-    ) -> BoxResult<BTreeMap<isize, Vec<(isize, isize)>>> {
-        Ok(io::BufReader::new(input)
-            .lines()
-            .try_fold(
-                (BTreeMap::new(), Coord(0, 0)),
-                |(mut map, mut coord), rs| {
-                    let s = rs.map_err(|_| AocError)?;
-                    let (_, [dir, len, colour]) = PATTERN.captures(&s).ok_or(AocError)?.extract();
-                    let dir = if use_colour {
-                        match colour.chars().last() {
-                            Some('3') => Ok(Dir::North),
-                            Some('1') => Ok(Dir::South),
-                            Some('2') => Ok(Dir::West),
-                            Some('0') => Ok(Dir::East),
-                            _ => Err(AocError),
-                        }
-                    } else {
-                        match dir {
-                            "U" => Ok(Dir::North),
-                            "D" => Ok(Dir::South),
-                            "L" => Ok(Dir::West),
-                            "R" => Ok(Dir::East),
-                            _ => Err(AocError),
-                        }
-                    }?;
-                    let len: isize = if use_colour {
-                        isize::from_str_radix(&colour[..(colour.len() - 1)], 16)?
-                    } else {
-                        len.parse()?
-                    };
-                    match dir {
-                        Dir::East => {
-                            let extent = map.entry(coord.1).or_insert(vec![]);
-                            (*extent).push((coord.0, coord.0 + len));
-                            (*extent).sort();
-                            coord.0 += len;
-                        }
-                        Dir::South => {
-                            coord.1 += len;
-                        }
-                        Dir::West => {
-                            let extent = map.entry(coord.1).or_insert(vec![]);
-                            (*extent).push((coord.0 - len, coord.0));
-                            (*extent).sort();
-                            coord.0 -= len;
-                        }
-                        Dir::North => {
-                            coord.1 -= len;
-                        }
-                    }
-                    BoxResult::Ok((map, coord))
-                },
-            )?
-            .0)
+    // The corners of the dug trench, which must lead back to the start.
+    fn parse_polygon(input: &mut dyn io::Read, use_colour: bool) -> BoxResult<Polygon> {
+        let mut vertices =
+            io::BufReader::new(input)
+                .lines()
+                .try_fold(vec![(0, 0)], |mut vertices, rs| {
+                    let (dir, len) = Self::parse_step(rs, use_colour)?;
+                    let (x, y) = *vertices.last().unwrap();
+                    vertices.push(match dir {
+                        Dir::East => (x + len, y),
+                        Dir::South => (x, y + len),
+                        Dir::West => (x - len, y),
+                        Dir::North => (x, y - len),
+                    });
+                    BoxResult::Ok(vertices)
+                })?;
+        if vertices.pop() != Some((0, 0)) {
+            Err(AocError)?;
+        }
+        Ok(Polygon::new(vertices))
     }
 
-    fn compute_naive(
-        map: HashMap<Coord, (Dir, Option<Dir>)>,
-        min: Coord,
-        max: Coord,
-    ) -> BoxResult<Output> {
-        Ok((min.1..=max.1)
-            .map(|y| {
-                let (_, sum, _) = (min.0..=max.0).fold(
-                    (false, 0, None),
-                    |(mut is_inside, mut sum, mut horizontal_from_north), x| {
-                        if let Some((dir, Some(from_dir))) = map.get(&Coord(x, y)) {
-                            match (from_dir, dir) {
-                                (Dir::North, Dir::North) | (Dir::South, Dir::South) => {
-                                    is_inside = !is_inside;
-                                }
-                                (Dir::South, Dir::East) | (Dir::West, Dir::North) => {
-                                    horizontal_from_north = Some(true);
-                                }
-                                (Dir::North, Dir::East) | (Dir::West, Dir::South) => {
-                                    horizontal_from_north = Some(false);
-                                }
-                                (Dir::North, Dir::West) | (Dir::East, Dir::South)
-                                    if horizontal_from_north == Some(true) =>
-                                {
-                                    is_inside = !is_inside;
-                                    horizontal_from_north = None;
-                                }
-                                (Dir::South, Dir::West) | (Dir::East, Dir::North)
-                                    if horizontal_from_north == Some(false) =>
-                                {
-                                    is_inside = !is_inside;
-                                    horizontal_from_north = None;
-                                }
-                                _ => {}
-                            }
-                            sum += 1;
-                        } else if is_inside {
-                            sum += 1;
-                        }
-                        (is_inside, sum, horizontal_from_north)
-                    },
-                );
-                sum
+    // The horizontal extents of the trench on each row, for the sweep.
+    fn extents(polygon: &Polygon) -> BTreeMap<isize, Vec<(isize, isize)>> {
+        polygon
+            .edges()
+            .filter(|(a, b)| a.1 == b.1 && a.0 != b.0)
+            .fold(BTreeMap::new(), |mut map, (a, b)| {
+                let extent = map.entry(a.1).or_insert(vec![]);
+                (*extent).push((a.0.min(b.0), a.0.max(b.0)));
+                (*extent).sort();
+                map
             })
-            .enumerate()
-            .map(|(i, s)| {
-                println!("{}: {}", i, s);
-                s
-            })
-            .sum())
     }
 
     fn union(a: &[(isize, isize)], b: &[(isize, isize)]) -> Vec<(isize, isize)> {
@@ -349,14 +224,26 @@ impl Day18 {
             .1)
     }
 
+    // The trench and the interior it encloses, by the shoelace formula and Pick's theorem.
+    fn dig(polygon: &Polygon) -> BoxResult<Output> {
+        if !polygon.is_simple() {
+            Err(AocError)?;
+        }
+        let area = polygon.lattice_points();
+        debug_assert_eq!(Self::compute(Self::extents(polygon)).ok(), Some(area));
+        Ok(area)
+    }
+
     fn part1_impl(&self, input: &mut dyn io::Read) -> BoxResult<Output> {
-        let all_extents = Self::parse(input, false)?;
-        Self::compute(all_extents)
+        Self::dig(&Self::parse_polygon(input, false)?)
     }
 
     fn part2_impl(&self, input: &mut dyn io::Read) -> BoxResult<Output> {
-        let all_extents = Self::parse(input, true)?;
-        Self::compute(all_extents)
+        Self::dig(&Self::parse_polygon(input, true)?)
+    }
+
+    fn svg_impl(&self, input: &mut dyn io::Read, use_colour: bool) -> BoxResult<String> {
+        Ok(Self::parse_polygon(input, use_colour)?.svg())
     }
 }
 
@@ -368,10 +255,7 @@ mod tests {
         assert_eq!(Day18 {}.part1_impl(&mut s.as_bytes()).ok(), Some(f));
     }
 
-    #[test]
-    fn part1() {
-        test1(
-            "R 6 (#70c710)
+    const EXAMPLE: &str = "R 6 (#70c710)
 D 5 (#0dc571)
 L 2 (#5713f0)
 D 2 (#d2c081)
@@ -384,9 +268,11 @@ U 2 (#caa171)
 R 2 (#7807d2)
 U 3 (#a77fa3)
 L 2 (#015232)
-U 2 (#7a21e3)",
-            62,
-        );
+U 2 (#7a21e3)";
+
+    #[test]
+    fn part1() {
+        test1(EXAMPLE, 62);
     }
 
     fn test2(s: &str, f: Output) {
@@ -395,22 +281,61 @@ U 2 (#7a21e3)",
 
     #[test]
     fn part2() {
-        test2(
-            "R 6 (#70c710)
-D 5 (#0dc571)
-L 2 (#5713f0)
-D 2 (#d2c081)
-R 2 (#59c680)
-D 2 (#411b91)
-L 5 (#8ceee2)
-U 2 (#caa173)
-L 1 (#1b58a2)
-U 2 (#caa171)
-R 2 (#7807d2)
-U 3 (#a77fa3)
-L 2 (#015232)
-U 2 (#7a21e3)",
-            952408144115,
+        test2(EXAMPLE, 952408144115);
+    }
+
+    // The sweep over row extents should agree with the polygon formulas.
+    #[test]
+    fn sweep() {
+        for s in [
+            EXAMPLE,
+            "R 4 (#000000)
+D 4 (#000000)
+L 4 (#000000)
+U 4 (#000000)",
+            "R 2 (#000000)
+U 2 (#000000)
+R 2 (#000000)
+D 5 (#000000)
+L 4 (#000000)
+U 3 (#000000)",
+        ] {
+            let polygon = Day18::parse_polygon(&mut s.as_bytes(), false).unwrap();
+            assert_eq!(
+                Day18::compute(Day18::extents(&polygon)).ok(),
+                Some(polygon.lattice_points())
+            );
+        }
+        let polygon = Day18::parse_polygon(&mut EXAMPLE.as_bytes(), true).unwrap();
+        assert_eq!(
+            Day18::compute(Day18::extents(&polygon)).ok(),
+            Some(952408144115)
         );
     }
+
+    #[test]
+    fn invalid() {
+        // Not back at the start.
+        assert!(Day18 {}
+            .part1_impl(&mut "R 4 (#000000)\nD 4 (#000000)\nL 4 (#000000)".as_bytes())
+            .is_err());
+        // Crossing itself.
+        assert!(Day18 {}
+            .part1_impl(
+                &mut "R 2 (#000000)
+D 2 (#000000)
+L 1 (#000000)
+U 3 (#000000)
+L 1 (#000000)
+D 1 (#000000)"
+                    .as_bytes()
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn svg() {
+        let svg = Day18 {}.svg_impl(&mut EXAMPLE.as_bytes(), false).unwrap();
+        assert!(svg.contains("points=\"0,0 6,0 6,5 4,5 4,7 6,7 6,9 1,9 1,7 0,7 0,5 2,5 2,2 0,2\""));
+    }
 }
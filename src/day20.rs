@@ -1,4 +1,6 @@
 use num::integer::lcm;
+use petgraph::dot::{Config, Dot};
+use petgraph::graphmap::DiGraphMap;
 use std::cell::RefCell;
 use std::collections::{HashMap, HashSet, VecDeque};
 use std::fmt::Debug;
@@ -23,6 +25,20 @@ impl Day for Day20 {
     fn part2(&self, input: &dyn Fn() -> Box<dyn io::Read>) {
         println!("{:?}", self.part2_impl(&mut *input()));
     }
+
+    fn dot(&self, input: &dyn Fn() -> Box<dyn io::Read>) {
+        match self.dot_impl(&mut *input()) {
+            Ok(dot) => print!("{}", dot),
+            Err(e) => println!("{:?}", e),
+        }
+    }
+
+    fn report(&self, input: &dyn Fn() -> Box<dyn io::Read>) {
+        match self.report_impl(&mut *input(), 1 << 14) {
+            Ok(report) => println!("{}", report),
+            Err(e) => println!("{:?}", e),
+        }
+    }
 }
 
 lazy_static! {
@@ -48,8 +64,12 @@ trait Mod: Debug {
     fn targets(&self) -> Vec<&str>;
     fn record_source(&mut self, source: &str);
     fn kind(&self) -> Kind;
+    fn state(&self) -> Vec<bool>;
+    fn restore(&mut self, state: &[bool]) -> BoxResult<()>;
 }
 
+type Network = HashMap<String, RefCell<Box<dyn Mod>>>;
+
 #[derive(Debug)]
 struct FlipFlop {
     metadata: Module,
@@ -79,6 +99,20 @@ impl Mod for FlipFlop {
     fn kind(&self) -> Kind {
         Kind::FlipFlop
     }
+
+    fn state(&self) -> Vec<bool> {
+        vec![self.state]
+    }
+
+    fn restore(&mut self, state: &[bool]) -> BoxResult<()> {
+        match state {
+            [state] => {
+                self.state = *state;
+                Ok(())
+            }
+            _ => Err(AocError.into()),
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -108,6 +142,26 @@ impl Mod for Conjunction {
     fn kind(&self) -> Kind {
         Kind::Conjunction
     }
+
+    // The memory is ordered by source name, to make the state independent of hashing order.
+    fn state(&self) -> Vec<bool> {
+        self.memory
+            .iter()
+            .sorted()
+            .map(|(_, memory)| *memory)
+            .collect_vec()
+    }
+
+    fn restore(&mut self, state: &[bool]) -> BoxResult<()> {
+        if state.len() != self.memory.len() {
+            return Err(AocError.into());
+        }
+        let sources = self.memory.keys().cloned().sorted().collect_vec();
+        for (source, memory) in sources.into_iter().zip(state) {
+            self.memory.insert(source, *memory);
+        }
+        Ok(())
+    }
 }
 
 #[derive(Debug)]
@@ -133,6 +187,93 @@ impl Mod for Broadcaster {
     fn kind(&self) -> Kind {
         Kind::Broadcaster
     }
+
+    fn state(&self) -> Vec<bool> {
+        vec![]
+    }
+
+    fn restore(&mut self, state: &[bool]) -> BoxResult<()> {
+        if state.is_empty() {
+            Ok(())
+        } else {
+            Err(AocError.into())
+        }
+    }
+}
+
+// A snapshot of the flip-flop states and conjunction memories of a set of modules.  The modules
+// are kept in name order and their states are packed one bit each, making for a compact key.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+struct Snapshot {
+    modules: Vec<(String, usize)>,
+    bits: Vec<u64>,
+}
+
+impl Snapshot {
+    fn take(network: &Network, names: &[&str]) -> BoxResult<Self> {
+        let mut modules = vec![];
+        let mut bits = vec![];
+        let mut len = 0;
+        for &name in names.iter().sorted().dedup() {
+            let state = network.get(name).ok_or(AocError)?.borrow().state();
+            for bit in &state {
+                if len % 64 == 0 {
+                    bits.push(0);
+                }
+                bits[len / 64] |= (*bit as u64) << (len % 64);
+                len += 1;
+            }
+            modules.push((name.to_string(), state.len()));
+        }
+        Ok(Snapshot { modules, bits })
+    }
+
+    // Only the packed bits, so keys are only comparable between snapshots of the same modules.
+    fn key(&self) -> &[u64] {
+        &self.bits
+    }
+
+    fn states(&self) -> impl Iterator<Item = (&str, Vec<bool>)> + '_ {
+        self.modules
+            .iter()
+            .scan(0, |offset, (name, len)| {
+                let start = *offset;
+                *offset += len;
+                Some((name.as_str(), start..*offset))
+            })
+            .map(|(name, range)| {
+                (
+                    name,
+                    range
+                        .map(|i| self.bits[i / 64] >> (i % 64) & 1 == 1)
+                        .collect_vec(),
+                )
+            })
+    }
+
+    fn restore(&self, network: &Network) -> BoxResult<()> {
+        self.states().try_for_each(|(name, state)| {
+            network
+                .get(name)
+                .ok_or(AocError)?
+                .borrow_mut()
+                .restore(&state)
+        })
+    }
+
+    // The names of the modules whose states differ between the snapshots, including those only
+    // present in one of them.
+    fn differences<'a>(&'a self, other: &'a Self) -> Vec<&'a str> {
+        let ours = self.states().collect::<HashMap<_, _>>();
+        let theirs = other.states().collect::<HashMap<_, _>>();
+        ours.keys()
+            .chain(theirs.keys())
+            .filter(|name| ours.get(*name) != theirs.get(*name))
+            .copied()
+            .sorted()
+            .dedup()
+            .collect_vec()
+    }
 }
 
 impl Day20 {
@@ -200,43 +341,75 @@ impl Day20 {
         })
     }
 
-    fn part1_impl(&self, input: &mut dyn io::Read) -> BoxResult<Output> {
-        let modules = Self::parse(input)?
+    fn network(input: &mut dyn io::Read) -> BoxResult<Network> {
+        Ok(Self::parse(input)?
             .0
             .into_iter()
             .map(|(k, v)| (k, RefCell::new(v)))
-            .collect::<HashMap<_, _>>();
+            .collect())
+    }
+
+    // Push the button once, letting the observer see every pulse sent.
+    fn press<F>(network: &Network, mut observe: F)
+    where
+        F: FnMut(&str, &str, bool),
+    {
+        let mut targets =
+            VecDeque::from([("button".to_string(), "broadcaster".to_string(), false)]);
+        while let Some((source, target, pulse)) = targets.pop_front() {
+            observe(&source, &target, pulse);
+            if let Some(module) = network.get(&target) {
+                let mut module = module.borrow_mut();
+                if let Some(pulse) = module.pulse(&source, pulse) {
+                    targets.extend(
+                        module
+                            .targets()
+                            .into_iter()
+                            .map(|new_target| (target.to_string(), new_target.to_string(), pulse)),
+                    );
+                }
+            }
+        }
+    }
+
+    fn part1_impl(&self, input: &mut dyn io::Read) -> BoxResult<Output> {
+        let network = Self::network(input)?;
         let mut cnt_low = 0 as Output;
         let mut cnt_high = 0 as Output;
-        for i in 0..1000 {
-            let mut targets =
-                VecDeque::from([("button".to_string(), "broadcaster".to_string(), false)]);
-            while let Some((source, target, pulse)) = targets.pop_front() {
-                if pulse {
-                    cnt_high += 1
-                } else {
-                    cnt_low += 1
-                }
-                if let Some(module) = modules.get(&target) {
-                    let mut module = module.borrow_mut();
-                    if let Some(pulse) = module.pulse(&source, pulse) {
-                        targets.extend(
-                            module
-                                .targets()
-                                .into_iter()
-                                .map(ToString::to_string)
-                                .map(|new_target| {
-                                    (target.to_string(), new_target.to_string(), pulse)
-                                })
-                                .collect_vec(),
-                        );
+        for _ in 0..1000 {
+            Self::press(
+                &network,
+                |_, _, pulse| {
+                    if pulse {
+                        cnt_high += 1
+                    } else {
+                        cnt_low += 1
                     }
-                }
-            }
+                },
+            );
         }
         Ok(cnt_low * cnt_high)
     }
 
+    // Push the button until the named modules are back in a state seen before, at most limit
+    // times.  Returns the number of presses before the cycle is entered and the cycle length.
+    fn find_state_cycle(
+        network: &Network,
+        names: &[&str],
+        limit: Output,
+    ) -> BoxResult<Option<(Output, Output)>> {
+        let mut seen = HashMap::from([(Snapshot::take(network, names)?.key().to_vec(), 0)]);
+        for i in 1..=limit {
+            Self::press(network, |_, _, _| {});
+            let snapshot = Snapshot::take(network, names)?;
+            if let Some(start) = seen.get(snapshot.key()) {
+                return Ok(Some((*start, i - start)));
+            }
+            seen.insert(snapshot.key().to_vec(), i);
+        }
+        Ok(None)
+    }
+
     fn reversed(
         modules: &HashMap<String, Box<dyn Mod>>,
     ) -> HashMap<String, HashSet<(String, Kind)>> {
@@ -259,6 +432,115 @@ impl Day20 {
         r
     }
 
+    // Render the module network in Graphviz DOT format, with the module kinds told apart by
+    // shape and the final rx sink highlighted.
+    fn dot_impl(&self, input: &mut dyn io::Read) -> BoxResult<String> {
+        let (modules, _) = Self::parse(input)?;
+        let reversed = Self::reversed(&modules);
+        let mut kinds = HashMap::new();
+        let mut graph = DiGraphMap::new();
+        for (target, sources) in &reversed {
+            for (source, kind) in sources {
+                kinds.insert(source.as_str(), *kind);
+                graph.add_edge(source.as_str(), target.as_str(), "");
+            }
+        }
+        // The broadcaster is only ever a source, so make sure it is present even without targets.
+        graph.add_node("broadcaster");
+        kinds.insert("broadcaster", Kind::Broadcaster);
+        Ok(format!(
+            "{}",
+            Dot::with_attr_getters(
+                &graph,
+                &[Config::EdgeNoLabel],
+                &|_, _| String::new(),
+                &|_, (name, _)| match kinds.get(name) {
+                    Some(Kind::Broadcaster) => "shape = doublecircle".to_string(),
+                    Some(Kind::FlipFlop) => "shape = box".to_string(),
+                    Some(Kind::Conjunction) => "shape = diamond".to_string(),
+                    None if name == "rx" => {
+                        "shape = doubleoctagon, style = filled, fillcolor = red".to_string()
+                    }
+                    None => "shape = plaintext".to_string(),
+                },
+            )
+        ))
+    }
+
+    // The modules upstream of the named one, including itself.
+    fn upstream<'a>(
+        reversed: &'a HashMap<String, HashSet<(String, Kind)>>,
+        name: &'a str,
+    ) -> Vec<&'a str> {
+        let mut seen = HashSet::from([name]);
+        let mut queue = VecDeque::from([name]);
+        while let Some(name) = queue.pop_front() {
+            for (source, _) in reversed.get(name).into_iter().flatten() {
+                if seen.insert(source) {
+                    queue.push_back(source);
+                }
+            }
+        }
+        seen.into_iter().sorted().collect_vec()
+    }
+
+    // How soon the network returns to a previous state, pressing the button at most limit times.
+    // If rx is fed by a conjunction of counters, each counter is looked at on its own, starting
+    // over from the initial state, as the network as a whole takes far too long.  When no state
+    // repeats, list the modules that differ from the initial state at the end.
+    fn report_impl(&self, input: &mut dyn io::Read, limit: Output) -> BoxResult<String> {
+        let (modules, _) = Self::parse(input)?;
+        let reversed = Self::reversed(&modules);
+        let all = modules.keys().cloned().sorted().collect_vec();
+        let all = all.iter().map(String::as_str).collect_vec();
+        let counters = reversed
+            .get("rx")
+            .into_iter()
+            .flatten()
+            .flat_map(|(parent, _)| reversed.get(parent).into_iter().flatten())
+            .map(|(counter, _)| counter.as_str())
+            .sorted()
+            .collect_vec();
+        let parts = if counters.is_empty() {
+            vec![("network", all.clone())]
+        } else {
+            counters
+                .into_iter()
+                .map(|counter| (counter, Self::upstream(&reversed, counter)))
+                .collect_vec()
+        };
+        let network = modules
+            .into_iter()
+            .map(|(k, v)| (k, RefCell::new(v)))
+            .collect::<Network>();
+        let initial = Snapshot::take(&network, &all)?;
+        parts
+            .iter()
+            .map(|(name, names)| {
+                initial.restore(&network)?;
+                Ok(match Self::find_state_cycle(&network, names, limit)? {
+                    Some((start, length)) => format!(
+                        "{}: {} modules, repeating every {} presses after {}",
+                        name,
+                        names.len(),
+                        length,
+                        start
+                    ),
+                    None => format!(
+                        "{}: {} modules, no repeat within {} presses, changed: {}",
+                        name,
+                        names.len(),
+                        limit,
+                        initial
+                            .differences(&Snapshot::take(&network, &all)?)
+                            .join(", ")
+                    ),
+                })
+            })
+            .collect::<BoxResult<Vec<_>>>()
+            .map(|lines| lines.join("\n"))
+    }
+
     fn part2_impl(&self, input: &mut dyn io::Read) -> BoxResult<Output> {
         let (m, all) = Self::parse(input)?;
         let reversed = Self::reversed(&m);
@@ -340,4 +622,109 @@ mod tests {
             11687500,
         );
     }
+
+    const EXAMPLE2: &str = "broadcaster -> a
+%a -> inv, con
+&inv -> b
+%b -> con
+&con -> output";
+
+    #[test]
+    fn snapshot() {
+        let network = Day20::network(&mut EXAMPLE2.as_bytes()).unwrap();
+        let all = ["a", "b", "broadcaster", "con", "inv"];
+        let initial = Snapshot::take(&network, &all).unwrap();
+        assert_eq!(initial.key(), &[0]);
+        Day20::press(&network, |_, _, _| {});
+        let pressed = Snapshot::take(&network, &all).unwrap();
+        assert_ne!(initial, pressed);
+        assert_eq!(initial.differences(&pressed), vec!["a", "b", "con", "inv"]);
+        initial.restore(&network).unwrap();
+        assert_eq!(Snapshot::take(&network, &all).unwrap(), initial);
+        let partial = Snapshot::take(&network, &["b"]).unwrap();
+        assert_eq!(initial.differences(&partial).len(), 4);
+        assert!(pressed
+            .restore(&Day20::network(&mut "%a -> b".as_bytes()).unwrap())
+            .is_err());
+    }
+
+    #[test]
+    fn state_cycle() {
+        let network = Day20::network(
+            &mut "broadcaster -> a, b, c
+%a -> b
+%b -> c
+%c -> inv
+&inv -> a"
+                .as_bytes(),
+        )
+        .unwrap();
+        assert_eq!(
+            Day20::find_state_cycle(&network, &["a", "b", "c", "inv"], 10).unwrap(),
+            Some((0, 1))
+        );
+        let network = Day20::network(&mut EXAMPLE2.as_bytes()).unwrap();
+        assert_eq!(
+            Day20::find_state_cycle(&network, &["a", "b", "con", "inv"], 10).unwrap(),
+            Some((0, 4))
+        );
+        assert_eq!(
+            Day20::find_state_cycle(&network, &["a"], 10).unwrap(),
+            Some((0, 2))
+        );
+        assert_eq!(
+            Day20::find_state_cycle(&network, &["a", "b", "con", "inv"], 3).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn report() {
+        assert_eq!(
+            Day20 {}.report_impl(&mut EXAMPLE2.as_bytes(), 10).unwrap(),
+            "network: 5 modules, repeating every 4 presses after 0"
+        );
+        assert_eq!(
+            Day20 {}.report_impl(&mut EXAMPLE2.as_bytes(), 3).unwrap(),
+            "network: 5 modules, no repeat within 3 presses, changed: a, con, inv"
+        );
+        // Two counters, of periods 2 and 4, feeding rx.
+        assert_eq!(
+            Day20 {}
+                .report_impl(
+                    &mut "broadcaster -> a, c
+%a -> na
+&na -> all
+%c -> d, nd
+%d -> nd
+&nd -> all
+&all -> rx"
+                        .as_bytes(),
+                    10
+                )
+                .unwrap(),
+            "na: 3 modules, repeating every 2 presses after 0
+nd: 4 modules, repeating every 4 presses after 0"
+        );
+    }
+
+    #[test]
+    fn dot() {
+        let dot = Day20 {}
+            .dot_impl(
+                &mut "broadcaster -> a
+%a -> inv, con
+&inv -> b
+%b -> con
+&con -> rx"
+                    .as_bytes(),
+            )
+            .unwrap();
+        assert!(dot.starts_with("digraph {"));
+        assert!(dot.contains("label = \"broadcaster\" shape = doublecircle"));
+        assert!(dot.contains("label = \"a\" shape = box"));
+        assert!(dot.contains("label = \"con\" shape = diamond"));
+        assert!(dot.contains("label = \"rx\" shape = doubleoctagon"));
+        assert_eq!(dot.matches(" -> ").count(), 6);
+    }
 }
@@ -1,5 +1,6 @@
 use crate::day::*;
-use std::collections::{HashMap, HashSet, VecDeque};
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
 
 pub struct Day21 {}
 
@@ -31,13 +32,12 @@ enum Dir {
 }
 
 impl Coord {
-    // Compute the next coordinate in a direction.
-    fn walk(self, dir: Dir, size: Coord, is_infinite: bool) -> Option<Self> {
-        if !is_infinite
-            && (self.0 == 0 && dir == Dir::West
-                || self.1 == 0 && dir == Dir::North
-                || self.0 == size.0 - 1 && dir == Dir::East
-                || self.1 == size.1 - 1 && dir == Dir::South)
+    // Compute the next coordinate in a direction, staying within an area of the given size.
+    fn walk(self, dir: Dir, size: Coord) -> Option<Self> {
+        if self.0 == 0 && dir == Dir::West
+            || self.1 == 0 && dir == Dir::North
+            || self.0 == size.0 - 1 && dir == Dir::East
+            || self.1 == size.1 - 1 && dir == Dir::South
         {
             None
         } else {
@@ -61,26 +61,6 @@ impl Coord {
     fn narrow(&self, size: Coord) -> Coord {
         Coord(self.0.rem_euclid(size.0), self.1.rem_euclid(size.1))
     }
-
-    fn moves(
-        self,
-        tiles: &HashMap<Coord, Tile>,
-        size: Coord,
-        is_infinite: bool,
-    ) -> Vec<(Dir, Coord)> {
-        [Dir::East, Dir::South, Dir::West, Dir::North]
-            .into_iter()
-            .flat_map(|dir| {
-                self.walk(dir, size, is_infinite)
-                    .filter(|coord| {
-                        tiles
-                            .get(&coord.narrow(size))
-                            .map_or(false, |tile| *tile == Tile::Plot)
-                    })
-                    .map(|coord| (dir, coord))
-            })
-            .collect_vec()
-    }
 }
 
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
@@ -89,403 +69,306 @@ enum Tile {
     Rock,
 }
 
-impl Day21 {
-    fn parse(input: &mut dyn io::Read) -> BoxResult<(HashMap<Coord, Tile>, Coord, Coord)> {
-        io::BufReader::new(input)
-            .lines()
-            .enumerate()
-            .try_fold(
-                (HashMap::new(), Coord(0, 0), None),
-                |(mut map, mut size, mut start), (y, rs)| {
-                    if let Ok(x) = rs.as_ref().map(|s| s.len()) {
-                        if x as isize > size.0 {
-                            size.0 = x as isize
-                        }
-                    }
-                    let _ = rs.map_err(|_| AocError).map(|s| {
-                        s.bytes().enumerate().for_each(|(x, b)| {
-                            map.insert(
-                                Coord(x as isize, y as isize),
-                                match b {
-                                    b'.' => Tile::Plot,
-                                    b'S' => {
-                                        start = Some(Coord(x as isize, y as isize));
-                                        Tile::Plot
-                                    }
-                                    b'#' => Tile::Rock,
-                                    _ => panic!(),
-                                },
-                            );
-                        })
-                    });
-                    size.1 = y as isize + 1;
-                    Ok((map, size, start))
-                },
-            )
-            .map(|(map, size, start)| (map, size, start.unwrap()))
+#[derive(Debug)]
+enum GardenError {
+    NoStart,
+    Ragged,
+    NotSquare,
+    Aperiodic,
+}
+
+impl fmt::Display for GardenError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GardenError::NoStart => write!(f, "the map has no starting position"),
+            GardenError::Ragged => write!(f, "the map rows differ in length"),
+            GardenError::NotSquare => write!(f, "the map must be square to tile the plane"),
+            GardenError::Aperiodic => {
+                write!(f, "the distances do not settle into a periodic pattern")
+            }
+        }
     }
+}
 
-    fn get_cycle(tiles: &HashMap<Coord, Tile>, size: Coord, start: Coord) -> (usize, usize) {
-        let mut seen = VecDeque::<(Vec<Coord>, usize)>::new();
-        (0usize..)
-            .try_fold(vec![start], |states, i| {
-                let states = states
-                    .into_iter()
-                    .flat_map(|coord| {
-                        coord
-                            .moves(tiles, size, true)
-                            .into_iter()
-                            .map(|(_, coord)| coord)
-                            .collect_vec()
-                    })
-                    .unique()
-                    .sorted()
-                    .collect_vec();
-
-                let zoom = |map: (isize, isize)| {
-                    states
-                        .iter()
-                        .copied()
-                        .filter(|&Coord(x, y)| {
-                            x.div_euclid(size.0) == map.0 && y.div_euclid(size.1) == map.1
-                        })
-                        .map(|Coord(x, y)| Coord(x.rem_euclid(size.0), y.rem_euclid(size.1)))
-                        .sorted()
-                        .collect_vec()
-                };
-
-                let zoomed = zoom((0, 0));
-                if let Some((_, start)) = seen.iter().find(|(state, _)| state == &zoomed) {
-                    Err((zoomed.len(), seen.pop_back().unwrap().0.len()))
-                } else {
-                    seen.push_back((zoomed, i));
-                    if seen.len() > 10 {
-                        seen.pop_front();
-                    }
-                    Ok(states)
-                }
-            })
-            .unwrap_err()
+impl error::Error for GardenError {}
+
+// The map, which for part 2 repeats infinitely in every direction.
+struct Garden {
+    tiles: HashMap<Coord, Tile>,
+    size: Coord,
+    start: Coord,
+}
+
+// The shortest distances from the start to every plot within a square block of
+// (2 * radius + 1)^2 copies of the map, centred on the original one.  A radius of zero gives
+// the map on its own.  As any plot can be left and returned to in two steps, it is reachable in
+// exactly n steps if its distance is at most n and of the same parity.
+struct DistanceField {
+    radius: isize,
+    size: Coord,
+    distances: Vec<Option<usize>>,
+    // The number of plots at distance n, n - 2, n - 4 and so on, up to the furthest plot.
+    reachable: Vec<Output>,
+}
+
+impl DistanceField {
+    fn width(&self) -> isize {
+        (2 * self.radius + 1) * self.size.0
     }
 
-    fn track(
-        tiles: &HashMap<Coord, Tile>,
-        size: Coord,
-        start: Coord,
-        steps: usize,
-        stable_size: Option<(usize, usize)>,
-    ) -> Output {
-        let mut stable_states = HashMap::new();
-        let mut entrypoints = HashMap::new();
-        let mut stable_maps = HashMap::new();
-        let mut memo_states = HashMap::new();
-
-        let states = (1..=steps).try_fold(vec![start], |states, step| {
-            //println!("step {}", step);
-            let states = states
-                .into_iter()
-                .flat_map(|coord| {
-                    coord
-                        .moves(tiles, size, stable_size.is_some())
-                        .into_iter()
-                        .map(|(_, coord)| coord)
-                        .filter(|coord| {
-                            !stable_maps.contains_key(&(
-                                coord.0.div_euclid(size.0),
-                                coord.1.div_euclid(size.1),
-                            ))
-                        })
-                        .collect_vec()
-                })
-                .unique()
-                .sorted()
-                .collect_vec();
-
-            // Look for new maps entered, and figure out entrypoints and their time.
-            let new_entrypoints = states
-                .iter()
-                .map(|coord| {
-                    (
-                        (coord.0.div_euclid(size.0), coord.1.div_euclid(size.1)),
-                        Coord(coord.0.rem_euclid(size.0), coord.1.rem_euclid(size.1)),
-                    )
-                })
-                .filter(|(map, _)| !entrypoints.contains_key(map))
-                .collect_vec();
-            if !new_entrypoints.is_empty() {
-                new_entrypoints.into_iter().for_each(|(map, coord)| {
-                    entrypoints
-                        .entry(map)
-                        .or_insert(HashSet::new())
-                        .insert((coord, step));
-                });
-            }
+    // The distance to a position within the map copy at the given tile offset.
+    fn get(&self, tile: (isize, isize), coord: Coord) -> Option<usize> {
+        let x = (tile.0 + self.radius) * self.size.0 + coord.0;
+        let y = (tile.1 + self.radius) * self.size.1 + coord.1;
+        self.distances[(y * self.width() + x) as usize]
+    }
+
+    fn tile(&self, tile: (isize, isize)) -> impl Iterator<Item = (Coord, usize)> + '_ {
+        (0..self.size.1)
+            .cartesian_product(0..self.size.0)
+            .flat_map(move |(y, x)| self.get(tile, Coord(x, y)).map(|d| (Coord(x, y), d)))
+    }
+
+    // The tile offsets at a given ring distance from the centre.
+    fn ring(radius: isize) -> impl Iterator<Item = (isize, isize)> {
+        (-radius..=radius)
+            .cartesian_product(-radius..=radius)
+            .filter(move |(x, y)| x.abs().max(y.abs()) == radius)
+    }
+
+    // The number of plots within the block reachable in exactly the given number of steps.
+    fn reachable(&self, steps: usize) -> Output {
+        let last = self.reachable.len() - 1;
+        if steps <= last {
+            self.reachable[steps]
+        } else if (steps - last).is_multiple_of(2) {
+            self.reachable[last]
+        } else {
+            last.checked_sub(1).map_or(0, |n| self.reachable[n])
+        }
+    }
+
+    fn reachable_range(&self, steps: impl Iterator<Item = usize>) -> Vec<Output> {
+        steps.map(|n| self.reachable(n)).collect_vec()
+    }
 
-            // zoom in on a specified map
-            let zoom = |map: (isize, isize)| {
-                states
-                    .iter()
-                    .copied()
-                    .filter(|&Coord(x, y)| {
-                        x.div_euclid(size.0) == map.0 && y.div_euclid(size.1) == map.1
-                    })
-                    .map(|Coord(x, y)| Coord(x.rem_euclid(size.0), y.rem_euclid(size.1)))
-                    .sorted()
-                    .collect_vec()
-            };
-
-            // Record stable states
-            if let Some(stable_size) = stable_size {
-                let zoomed = zoom((0, 0));
-                let cnt = zoomed.len();
-                if cnt == stable_size.0 || cnt == stable_size.1 {
-                    if stable_states
-                        .insert(zoomed.len(), cnt == stable_size.0)
-                        .is_none()
+    // Any path leaving the block passes its outer ring of tiles, so the block knows every plot
+    // reachable in fewer steps than the nearest plot on that ring.
+    fn horizon(&self) -> usize {
+        Self::ring(self.radius)
+            .flat_map(|tile| self.tile(tile).map(|(_, d)| d))
+            .min()
+            .unwrap_or(usize::MAX)
+    }
+}
+
+// Among the tiles k = 1, 2, ... further out from a tile on the ring, count those where the plot
+// at distance d + k * width is reachable in exactly the given number of steps.  Beyond a corner
+// of the ring there are k + 1 tiles at k tile widths, so those are weighted accordingly.
+fn series(d: usize, steps: usize, width: usize, is_corner: bool) -> Output {
+    if d > steps {
+        return 0;
+    }
+    let rem = steps - d;
+    let last = rem / width;
+    // Only every other k has the right parity if the width is odd.
+    let (first, step) = match (width % 2, rem % 2) {
+        (0, 0) => (1, 1),
+        (0, _) => return 0,
+        (_, 1) => (1, 2),
+        _ => (2, 2),
+    };
+    if first > last {
+        return 0;
+    }
+    let count = (last - first) / step + 1;
+    if is_corner {
+        count * (first + 1) + step * count * (count - 1) / 2
+    } else {
+        count
+    }
+}
+
+impl Garden {
+    // Breadth-first search from the start throughout a block of copies of the map.
+    fn distances(&self, radius: isize) -> DistanceField {
+        let mut field = DistanceField {
+            radius,
+            size: self.size,
+            distances: vec![],
+            reachable: vec![],
+        };
+        let size = Coord(field.width(), (2 * radius + 1) * self.size.1);
+        field.distances = vec![None; (size.0 * size.1) as usize];
+        let index = |coord: Coord| (coord.1 * size.0 + coord.0) as usize;
+        // Look the tiles up in a flat copy of the map, as the block may be large.
+        let is_plot = (0..self.size.1)
+            .cartesian_product(0..self.size.0)
+            .map(|(y, x)| self.tiles[&Coord(x, y)] == Tile::Plot)
+            .collect_vec();
+        let start = Coord(
+            radius * self.size.0 + self.start.0,
+            radius * self.size.1 + self.start.1,
+        );
+        field.distances[index(start)] = Some(0);
+        let mut queue = VecDeque::from([(start, 0)]);
+        while let Some((coord, d)) = queue.pop_front() {
+            for dir in [Dir::East, Dir::South, Dir::West, Dir::North] {
+                if let Some(next) = coord.walk(dir, size) {
+                    let Coord(x, y) = next.narrow(self.size);
+                    if is_plot[(y * self.size.0 + x) as usize]
+                        && field.distances[index(next)].is_none()
                     {
-                        //println!("step {} cnt {}", step, cnt);
-                        Self::print(tiles, size, &zoomed, (0, 0));
+                        field.distances[index(next)] = Some(d + 1);
+                        queue.push_back((next, d + 1));
                     }
                 }
             }
+        }
+        let furthest = field.distances.iter().flatten().max().copied().unwrap_or(0);
+        field.reachable = vec![0; furthest + 1];
+        for &d in field.distances.iter().flatten() {
+            field.reachable[d] += 1;
+        }
+        for n in 2..=furthest {
+            field.reachable[n] += field.reachable[n - 2];
+        }
+        field
+    }
 
-            for map in [
-                (0, -1),
-                (1, 0),
-                (0, 1),
-                (-1, 0),
-                (1, -1),
-                (1, 1),
-                (-1, 1),
-                (-1, -1),
-            ] {
-                let zoomed = zoom(map);
-                if !zoomed.is_empty() {
-                    let entry = entrypoints
-                        .get(&map)
-                        .unwrap()
-                        .iter()
-                        .map(|(_, entry)| entry)
-                        .min()
-                        .unwrap();
-                    memo_states.insert((map, step - *entry), zoom(map).len());
-                }
-            }
+    // The smallest block that knows every plot reachable in the given number of steps.
+    fn distances_covering(&self, steps: usize) -> DistanceField {
+        (steps as isize / self.size.0.min(self.size.1) + 1..)
+            .map(|radius| self.distances(radius))
+            .find(|field| field.horizon() > steps)
+            .unwrap()
+    }
 
-            // Check if a formerly unstable map has reached a stable state and if so
-            // record it with its time to stability.
-            let new_stable_maps = states
-                .iter()
-                .map(|coord| {
-                    let map = (coord.0.div_euclid(size.0), coord.1.div_euclid(size.1));
-                    (map, zoom(map))
-                })
-                .filter(|(map, states)| !stable_maps.contains_key(map))
-                .flat_map(|(map, states)| {
-                    stable_states.get(&states.len()).map(|lower| (map, lower))
-                })
-                .collect_vec();
-            if !new_stable_maps.is_empty() {
-                new_stable_maps.into_iter().for_each(|(map, lower)| {
-                    let entrypoint = entrypoints.get(&map).unwrap().iter().next().unwrap();
-                    if stable_maps
-                        .entry(map)
-                        .or_insert(HashSet::new())
-                        .insert((step - entrypoint.1, entrypoint.0))
-                    {
-                        //println!("new stable map {:?} at {} {}", map, step, lower);
-                    }
-                });
-            }
-            // We have all info we need when the inner 3x3 box is stable.
-            if (0..9)
-                .map(|i| (i % 3 - 1, i / 3 - 1))
-                .all(|map| stable_maps.contains_key(&map))
-            {
-                Err(())
-            } else {
-                Ok(states)
-            }
-        });
-        if let Ok(states) = states {
-            states.len()
-        } else {
-            // Assume step count 26501365 then the number of stable maps will be
-            // 202299 ^ 2 * odd (low) stability
-            // + 202300 ^ 2 * even (high) stability
-            // + 202299 * sum of the four corner maps' memoized state count for step 130
-            // + 202300 * sum of the four corner maps' memoized state count for step 64
-            // + sum of the four non-corner maps' memoized state count for step 65
-            assert_eq!(size.0, size.1);
-            let stable_maps_width = steps / size.0 as Output;
-            stable_maps_width * stable_maps_width * stable_size.unwrap().0
-                + (stable_maps_width + 1) * (stable_maps_width + 1) * stable_size.unwrap().1
-                + stable_maps_width
-                    * ([(-1, -1), (-1, 1), (1, -1), (1, 1)]
-                        .iter()
-                        .map(|map| memo_states.get(&(*map, 130)).unwrap())
-                        .sum::<Output>())
-                + (stable_maps_width + 1)
-                    * ([(-1, -1), (-1, 1), (1, -1), (1, 1)]
-                        .iter()
-                        .map(|map| memo_states.get(&(*map, 64)).unwrap())
-                        .sum::<Output>())
-                + ([(0, -1), (-1, 0), (1, 0), (0, 1)]
-                    .iter()
-                    .map(|map| memo_states.get(&(*map, 65)).unwrap())
-                    .sum::<Output>())
+    // The count after r + k * width steps is quadratic in k once the frontier has spread over
+    // a few tiles.  Sample the first periods, and extrapolate from the first window of them
+    // from which on every window is quadratic, if there are at least two such windows.
+    fn fit(&self, steps: usize) -> Option<Output> {
+        const SAMPLES: usize = 11;
+        let width = self.size.0 as usize;
+        let (k, r) = (steps / width, steps % width);
+        let field = self.distances_covering(r + (SAMPLES - 1) * width);
+        let f = field
+            .reachable_range((0..SAMPLES).map(|k| r + k * width))
+            .into_iter()
+            .map(|count| count as i128)
+            .collect_vec();
+        if k < SAMPLES {
+            return Some(f[k] as Output);
         }
+        let is_quadratic = |i: usize| f[i + 3] - 3 * f[i + 2] + 3 * f[i + 1] - f[i] == 0;
+        let first = (0..SAMPLES - 4).find(|&i| (i..SAMPLES - 3).all(is_quadratic))?;
+        // Newton's forward differences.
+        let t = (k - first) as i128;
+        let (f0, f1, f2) = (f[first], f[first + 1], f[first + 2]);
+        let count = f0 + t * (f1 - f0) + t * (t - 1) / 2 * (f2 - 2 * f1 + f0);
+        count.try_into().ok()
     }
 
-    fn print(tiles: &HashMap<Coord, Tile>, size: Coord, states: &[Coord], map: (isize, isize)) {
-        for y in 0..size.1 {
-            let s = (0..size.0).fold(String::new(), |mut s, x| {
-                let coord = Coord(map.0 * size.0 + x, map.1 * size.1 + y);
-                s.push_str(match tiles.get(&coord) {
-                    None => "",
-                    Some(Tile::Plot) => {
-                        if states.contains(&coord) {
-                            "O"
-                        } else {
-                            "."
-                        }
-                    }
-                    Some(Tile::Rock) => "#",
+    // Assume that beyond some ring of tiles, each map copy has the distances of the closest
+    // copy on the ring, plus a tile width for every tile further out.  Check this on the next
+    // ring, and count the tiles within the ring directly and those beyond by series.  This
+    // fails when crossing a tile costs more than its width, e.g. when rocks on its edges force
+    // detours, however far out the ring.
+    fn classify(&self, steps: usize) -> Result<Output, GardenError> {
+        const MAX_RADIUS: isize = 8;
+        let width = self.size.0 as usize;
+        // One block for all rings, with a margin so the outermost checked ring has its true
+        // distances.
+        let field = self.distances(MAX_RADIUS + 2);
+        (1..=MAX_RADIUS)
+            .find_map(|radius| {
+                let is_periodic = DistanceField::ring(radius + 1).all(|tile| {
+                    let inner = (tile.0.clamp(-radius, radius), tile.1.clamp(-radius, radius));
+                    let extra =
+                        (tile.0 - inner.0).unsigned_abs() + (tile.1 - inner.1).unsigned_abs();
+                    (0..self.size.1)
+                        .cartesian_product(0..self.size.0)
+                        .all(|(y, x)| {
+                            field.get(tile, Coord(x, y))
+                                == field.get(inner, Coord(x, y)).map(|d| d + extra * width)
+                        })
                 });
-                s
-            });
-            //println!("{}", s);
-        }
+                is_periodic.then(|| {
+                    let inside = (0..=radius)
+                        .flat_map(DistanceField::ring)
+                        .flat_map(|tile| field.tile(tile))
+                        .filter(|&(_, d)| d <= steps && (steps - d).is_multiple_of(2))
+                        .count();
+                    let outside = DistanceField::ring(radius)
+                        .flat_map(|tile| {
+                            let is_corner = tile.0.abs() == tile.1.abs();
+                            field
+                                .tile(tile)
+                                .map(move |(_, d)| series(d, steps, width, is_corner))
+                                .collect_vec()
+                        })
+                        .sum::<Output>();
+                    inside + outside
+                })
+            })
+            .ok_or(GardenError::Aperiodic)
     }
+}
 
-    fn part1_impl(&self, input: &mut dyn io::Read, steps: usize) -> BoxResult<Output> {
-        let (tiles, size, start) = Self::parse(input)?;
-        Ok(Self::track(&tiles, size, start, steps, None))
+impl Day21 {
+    fn parse(input: &mut dyn io::Read) -> BoxResult<Garden> {
+        let (tiles, size, start) = io::BufReader::new(input).lines().enumerate().try_fold(
+            (HashMap::new(), Coord(0, 0), None),
+            |(mut map, mut size, mut start), (y, rs)| {
+                let s = rs?;
+                if y > 0 && s.len() as isize != size.0 {
+                    return Err(GardenError::Ragged.into());
+                }
+                size.0 = s.len() as isize;
+                for (x, b) in s.bytes().enumerate() {
+                    map.insert(
+                        Coord(x as isize, y as isize),
+                        match b {
+                            b'.' => Tile::Plot,
+                            b'S' => {
+                                start = Some(Coord(x as isize, y as isize));
+                                Tile::Plot
+                            }
+                            b'#' => Tile::Rock,
+                            _ => Err(AocError)?,
+                        },
+                    );
+                }
+                size.1 = y as isize + 1;
+                Ok::<_, Box<dyn error::Error>>((map, size, start))
+            },
+        )?;
+        Ok(Garden {
+            tiles,
+            size,
+            start: start.ok_or(GardenError::NoStart)?,
+        })
     }
 
-    // fn time_to_stable(
-    //     tiles: &HashMap<Coord, Tile>,
-    //     size: Coord,
-    //     start: Coord,
-    //     stable_sizes: (usize, usize),
-    // ) -> usize {
-    //     (1..)
-    //         .try_fold(vec![start], |states, i| {
-    //             let states = states
-    //                 .into_iter()
-    //                 .flat_map(|coord| {
-    //                     coord
-    //                         .moves(tiles, size, true)
-    //                         .into_iter()
-    //                         .map(|(_, coord)| coord)
-    //                         .collect_vec()
-    //                 })
-    //                 .unique()
-    //                 .sorted()
-    //                 .collect_vec();
-    //
-    //             // zoom in on a specified map
-    //             let zoom = |map: (isize, isize)| {
-    //                 states
-    //                     .iter()
-    //                     .copied()
-    //                     .filter(|&Coord(x, y)| {
-    //                         x.div_euclid(size.0) == map.0 && y.div_euclid(size.1) == map.1
-    //                     })
-    //                     .map(|Coord(x, y)| Coord(x.rem_euclid(size.0), y.rem_euclid(size.1)))
-    //                     .sorted()
-    //                     .collect_vec()
-    //             };
-    //
-    //             let cnt = zoom((0, 0)).len();
-    //             if cnt == stable_sizes.0 || cnt == stable_sizes.1 {
-    //                 Err(i)
-    //             } else {
-    //                 Ok(states)
-    //             }
-    //         })
-    //         .unwrap_err()
-    // }
-    //
-    // fn time_to_corners(
-    //     tiles: &HashMap<Coord, Tile>,
-    //     size: Coord,
-    //     start: Coord,
-    // ) -> HashMap<Coord, usize> {
-    //     (1..)
-    //         .try_fold(
-    //             (
-    //                 vec![start],
-    //                 HashSet::from([
-    //                     Coord(0, 0),
-    //                     Coord(0, size.1 - 1),
-    //                     Coord(size.0 - 1, 0),
-    //                     Coord(size.0 - 1, size.1 - 1),
-    //                 ]),
-    //                 HashMap::new(),
-    //             ),
-    //             |(states, mut corners, mut map), i| {
-    //                 let states = states
-    //                     .into_iter()
-    //                     .flat_map(|coord| {
-    //                         coord
-    //                             .moves(tiles, size, true)
-    //                             .into_iter()
-    //                             .map(|(_, coord)| coord)
-    //                             .collect_vec()
-    //                     })
-    //                     .unique()
-    //                     .sorted()
-    //                     .map(|coord| {
-    //                         if corners.contains(&coord) {
-    //                             corners.remove(&coord);
-    //                             map.insert(coord, i);
-    //                         }
-    //                         coord
-    //                     })
-    //                     .collect_vec();
-    //                 if corners.is_empty() {
-    //                     Err(map)
-    //                 } else {
-    //                     Ok((states, corners, map))
-    //                 }
-    //             },
-    //         )
-    //         .unwrap_err()
-    // }
-    //
-    // fn time_to_stable_map(
-    //     tiles: &HashMap<Coord, Tile>,
-    //     size: Coord,
-    //     stable_sizes: (usize, usize),
-    // ) -> HashMap<Coord, usize> {
-    //     [
-    //         Coord(0, 0),
-    //         Coord(0, size.1 - 1),
-    //         Coord(size.0 - 1, 0),
-    //         Coord(size.0 - 1, size.1 - 1),
-    //     ]
-    //     .into_iter()
-    //     .map(|coord| {
-    //         (
-    //             coord,
-    //             Self::time_to_stable(tiles, size, coord, stable_sizes),
-    //         )
-    //     })
-    //     .collect()
-    // }
+    fn part1_impl(&self, input: &mut dyn io::Read, steps: usize) -> BoxResult<Output> {
+        let garden = Self::parse(input)?;
+        Ok(garden.distances(0).reachable(steps))
+    }
 
+    // Few steps are counted directly, otherwise extrapolate over periods of the map width,
+    // or failing that, over the tiles of the plane.
     fn part2_impl(&self, input: &mut dyn io::Read, steps: usize) -> BoxResult<Output> {
-        let (tiles, size, start) = Self::parse(input)?;
-
-        let stable_sizes = Self::get_cycle(&tiles, size, start);
-        //println!("cycle: {:?}", stable_sizes);
-        // let time_to_stable_map = Self::time_to_stable_map(&tiles, size, stable_sizes);
-        // println!("time_to_stable_map: {:?}", time_to_stable_map);
-        // let time_to_corners = Self::time_to_corners(&tiles, size, start);
-        // println!("time_to_corners: {:?}", time_to_corners);
-
-        Ok(Self::track(&tiles, size, start, steps, Some(stable_sizes)))
+        let garden = Self::parse(input)?;
+        if garden.size.0 != garden.size.1 {
+            return Err(GardenError::NotSquare.into());
+        }
+        if steps < 5 * garden.size.0 as usize {
+            return Ok(garden.distances_covering(steps).reachable(steps));
+        }
+        match garden.fit(steps) {
+            Some(count) => Ok(count),
+            None => Ok(garden.classify(steps)?),
+        }
     }
 }
 
@@ -497,10 +380,7 @@ mod tests {
         assert_eq!(Day21 {}.part1_impl(&mut s.as_bytes(), steps).ok(), Some(f));
     }
 
-    #[test]
-    fn part1() {
-        test1(
-            "...........
+    const EXAMPLE: &str = "...........
 .....###.#.
 .###.##..#.
 ..#.#...#..
@@ -510,9 +390,31 @@ mod tests {
 .......##..
 .##.#.####.
 .##..##.##.
-...........",
-            6,
-            16,
+...........";
+
+    #[test]
+    fn part1() {
+        test1(EXAMPLE, 6, 16);
+    }
+
+    #[test]
+    fn distances() {
+        let garden = Day21::parse(&mut EXAMPLE.as_bytes()).unwrap();
+        let field = garden.distances(0);
+        assert_eq!(field.get((0, 0), Coord(5, 5)), Some(0));
+        assert_eq!(field.get((0, 0), Coord(5, 1)), None);
+        assert_eq!(field.reachable_range(0..=6), vec![1, 2, 4, 6, 9, 13, 16]);
+        // Once the whole map is reached, the count alternates between the two parities.
+        assert_eq!(field.reachable(1000), field.reachable(1002));
+        assert_eq!(
+            field.reachable(1000) + field.reachable(1001),
+            field.distances.iter().flatten().count()
+        );
+        assert_eq!(
+            garden
+                .distances_covering(100)
+                .reachable_range([6, 10, 50, 100].into_iter()),
+            vec![16, 50, 1594, 6536]
         );
     }
 
@@ -522,95 +424,72 @@ mod tests {
 
     #[test]
     fn part2() {
-        test2(
-            "...........
-        .....###.#.
-        .###.##..#.
-        ..#.#...#..
-        ....#.#....
-        .##..S####.
-        .##..#...#.
-        .......##..
-        .##.#.####.
-        .##..##.##.
-        ...........",
-            6,
-            16,
-        );
-        test2(
-            "...........
-        .....###.#.
-        .###.##..#.
-        ..#.#...#..
-        ....#.#....
-        .##..S####.
-        .##..#...#.
-        .......##..
-        .##.#.####.
-        .##..##.##.
-        ...........",
-            10,
-            50,
-        );
-        test2(
-            "...........
-.....###.#.
-.###.##..#.
-..#.#...#..
-....#.#....
-.##..S####.
-.##..#...#.
-.......##..
-.##.#.####.
-.##..##.##.
-...........",
-            50,
-            1594,
-        );
-        test2(
-            "...........
-.....###.#.
-.###.##..#.
-..#.#...#..
-....#.#....
-.##..S####.
-.##..#...#.
-.......##..
-.##.#.####.
-.##..##.##.
-...........",
-            100,
-            6536,
-        );
-        test2(
-            "...........
-.....###.#.
-.###.##..#.
-..#.#...#..
-....#.#....
-.##..S####.
-.##..#...#.
-.......##..
-.##.#.####.
-.##..##.##.
-...........",
-            1000,
-            668697,
-        );
-        test2(
-            "...........
-.....###.#.
-.###.##..#.
-..#.#...#..
-....#.#....
-.##..S####.
-.##..#...#.
-.......##..
-.##.#.####.
-.##..##.##.
-...........",
-            5000,
-            16733044,
-        );
+        test2(EXAMPLE, 6, 16);
+        test2(EXAMPLE, 10, 50);
+        test2(EXAMPLE, 50, 1594);
+        test2(EXAMPLE, 100, 6536);
+        test2(EXAMPLE, 500, 167004);
+        test2(EXAMPLE, 1000, 668697);
+        test2(EXAMPLE, 5000, 16733044);
+    }
+
+    // A map with the start off centre and no clear row or column through it.
+    const OFF_CENTRE: &str = ".......
+.#.#.#.
+..#...#
+.#S.#..
+...#...
+.#...#.
+.......";
+
+    #[test]
+    fn general() {
+        let garden = Day21::parse(&mut OFF_CENTRE.as_bytes()).unwrap();
+        for steps in [100, 151, 250] {
+            let count = garden.distances_covering(steps).reachable(steps);
+            assert_eq!(garden.fit(steps), Some(count));
+            assert_eq!(garden.classify(steps).ok(), Some(count));
+        }
+        let garden = Day21::parse(&mut EXAMPLE.as_bytes()).unwrap();
+        for steps in [500, 1000, 5000] {
+            assert_eq!(garden.fit(steps), garden.classify(steps).ok());
+        }
+    }
+
+    #[test]
+    fn large() {
+        // Pseudo-random maps with the start off centre, compared with counting directly.
+        let mut answered = 0;
+        for seed in 0..10 {
+            for width in 9..=10 {
+                let mut s = Utils::random_grid(seed, width, width, "#.....").into_bytes();
+                s[width + 2] = b'S';
+                let s = String::from_utf8(s).unwrap();
+                let field = Day21::parse(&mut s.as_bytes())
+                    .unwrap()
+                    .distances_covering(20 * width);
+                for steps in [6 * width + 1, 13 * width + 3, 20 * width] {
+                    if let Ok(count) = (Day21 {}).part2_impl(&mut s.as_bytes(), steps) {
+                        assert_eq!(count, field.reachable(steps), "{} steps in\n{}", steps, s);
+                        answered += 1;
+                    }
+                }
+            }
+        }
+        assert!(answered > 40);
+    }
+
+    #[test]
+    fn errors() {
+        assert!(Day21 {}.part2_impl(&mut "...\n...".as_bytes(), 10).is_err());
+        assert!(Day21 {}
+            .part2_impl(&mut "..\n.S\n..".as_bytes(), 10)
+            .is_err());
+        assert!(Day21 {}
+            .part2_impl(&mut "...\n.S\n...".as_bytes(), 10)
+            .is_err());
+        assert!(Day21 {}
+            .part2_impl(&mut "..x\n.S.\n...".as_bytes(), 10)
+            .is_err());
     }
 }
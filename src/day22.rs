@@ -1,6 +1,8 @@
-use std::cmp::Ordering;
+use petgraph::algo::dominators::simple_fast;
+use petgraph::graphmap::DiGraphMap;
+use std::cmp::{Ordering, Reverse};
 use std::collections::{HashMap, HashSet};
-use std::fmt::Debug;
+use std::fmt::{self, Debug, Display};
 
 use regex::Regex;
 
@@ -22,18 +24,42 @@ impl Day for Day22 {
     fn part2(&self, input: &dyn Fn() -> Box<dyn io::Read>) {
         println!("{:?}", self.part2_impl(&mut *input()));
     }
+
+    fn report(&self, input: &dyn Fn() -> Box<dyn io::Read>) {
+        match self.report_impl(&mut *input()) {
+            Ok(mut report) => {
+                report.sort_by_key(|entry| (Reverse(entry.falls), entry.id));
+                for entry in report {
+                    println!(
+                        "{} {}: {} falling, {}, rests on {:?}, supports {:?}",
+                        entry.id,
+                        entry.brick,
+                        entry.falls,
+                        if entry.is_safe { "safe" } else { "unsafe" },
+                        entry.rests_on,
+                        entry.supports
+                    );
+                }
+            }
+            Err(e) => println!("{:?}", e),
+        }
+    }
 }
 
 lazy_static! {
-    static ref PATTERN: Regex = Regex::new("^(\\d),(\\d),(\\d+)~(\\d),(\\d),(\\d+)$").unwrap();
+    static ref PATTERN: Regex = Regex::new("^(\\d+),(\\d+),(\\d+)~(\\d+),(\\d+),(\\d+)$").unwrap();
 }
 
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
-struct Brick((usize, usize, usize), (usize, usize, usize));
-
-impl PartialEq<Brick> for &mut Brick {
-    fn eq(&self, other: &Brick) -> bool {
-        (*self).eq(other)
+pub struct Brick((usize, usize, usize), (usize, usize, usize));
+
+impl Display for Brick {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{},{},{}~{},{},{}",
+            self.0 .0, self.0 .1, self.0 .2, self.1 .0, self.1 .1, self.1 .2
+        )
     }
 }
 
@@ -49,62 +75,6 @@ impl Ord for Brick {
 }
 
 impl Brick {
-    fn drop(&mut self, support_map: &SupportMap) {
-        // XXX BROKEN! Would need rebuilding the support map
-        while !self.is_supported(support_map) {
-            self.drop_one();
-        }
-    }
-
-    fn drop_one(&mut self) {
-        //println!("brick {:?}", self);
-        self.0 .2 -= 1;
-        self.1 .2 -= 1;
-        //println!("  dropped to {:?}", self);
-    }
-
-    fn is_supported(&self, support_map: &SupportMap) -> bool {
-        self.min_z() == 1
-            || support_map
-                .iter()
-                .any(|(_, supportees)| supportees.contains(self))
-    }
-
-    fn is_redundant(&self, support_map: &SupportMap) -> bool {
-        // Find out if brick is only supporting others that are supported by someone else.
-        support_map.get(self).map_or(true, |supportees| {
-            supportees.iter().all(|supportee| {
-                support_map
-                    .iter()
-                    .filter(|(_, bricks)| bricks.contains(supportee))
-                    .count()
-                    > 1
-            })
-        })
-    }
-
-    fn covers(&self, other: &Brick) -> bool {
-        self.x_interleave(other) && self.y_interleave(other)
-    }
-
-    fn x_interleave(&self, other: &Brick) -> bool {
-        //        println!("x_interleave: {:?} {:?}", self, other);
-        let rv = (self.min_x()..=self.max_x()).contains(&other.min_x())
-            || (self.min_x()..=self.max_x()).contains(&other.max_x())
-            || (self.min_x() > other.min_x() && self.max_x() < other.max_x());
-        //        println!("  -> {}", rv);
-        rv
-    }
-
-    fn y_interleave(&self, other: &Brick) -> bool {
-        //        println!("y_interleave: {:?} {:?}", self, other);
-        let rv = (self.min_y()..=self.max_y()).contains(&other.min_y())
-            || (self.min_y()..=self.max_y()).contains(&other.max_y())
-            || (self.min_y() > other.min_y() && self.max_y() < other.max_y());
-        //        println!("  -> {}", rv);
-        rv
-    }
-
     fn min_x(&self) -> usize {
         usize::min(self.0 .0, self.1 .0)
     }
@@ -128,18 +98,91 @@ impl Brick {
     fn max_z(&self) -> usize {
         usize::max(self.0 .2, self.1 .2)
     }
+
+    fn footprint(&self) -> impl Iterator<Item = (usize, usize)> {
+        (self.min_x()..=self.max_x()).cartesian_product(self.min_y()..=self.max_y())
+    }
+
+    fn cubes(&self) -> impl Iterator<Item = (usize, usize, usize)> + '_ {
+        self.footprint()
+            .cartesian_product(self.min_z()..=self.max_z())
+            .map(|((x, y), z)| (x, y, z))
+    }
+}
+
+// Who supports whom among settled bricks, the bricks identified by their index.
+#[derive(Debug, Default)]
+pub struct SupportGraph {
+    supports: Vec<Vec<usize>>,
+    rests_on: Vec<Vec<usize>>,
+}
+
+impl SupportGraph {
+    pub fn supports(&self, brick: usize) -> &[usize] {
+        &self.supports[brick]
+    }
+
+    pub fn rests_on(&self, brick: usize) -> &[usize] {
+        &self.rests_on[brick]
+    }
+
+    // A brick can be disintegrated safely if all the bricks it supports rest on others as well.
+    pub fn is_redundant(&self, brick: usize) -> bool {
+        self.supports[brick]
+            .iter()
+            .all(|&other| self.rests_on[other].len() > 1)
+    }
+
+    // For each brick, the number of other bricks falling if it is disintegrated.  Those are the
+    // bricks it dominates, i.e. which are only held up from the ground through it.
+    pub fn falls(&self) -> Vec<Output> {
+        let mut graph = DiGraphMap::new();
+        graph.add_node(None);
+        for (brick, below) in self.rests_on.iter().enumerate() {
+            graph.add_node(Some(brick));
+            if below.is_empty() {
+                graph.add_edge(None, Some(brick), ());
+            }
+            for &other in below {
+                graph.add_edge(Some(other), Some(brick), ());
+            }
+        }
+        let dominators = simple_fast(&graph, None);
+        // A brick only rests on bricks settled before it, and so is dominated by those only.
+        let mut dominated = vec![1; self.rests_on.len()];
+        for brick in (0..self.rests_on.len()).rev() {
+            if let Some(Some(dominator)) = dominators.immediate_dominator(Some(brick)) {
+                dominated[dominator] += dominated[brick];
+            }
+        }
+        dominated.into_iter().map(|count| count - 1).collect_vec()
+    }
 }
 
-type SupportMap = HashMap<Brick, HashSet<Brick>>;
+// What happens to the structure if a brick is disintegrated.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Impact {
+    pub id: usize,
+    pub brick: Brick,
+    pub supports: Vec<usize>,
+    pub rests_on: Vec<usize>,
+    pub is_safe: bool,
+    pub falls: Output,
+}
 
 impl Day22 {
     fn parse_brick(spec: BoxResult<String>) -> BoxResult<Brick> {
         spec.and_then(|s| {
             let (_, [x0, y0, z0, x1, y1, z1]) = PATTERN.captures(&s).ok_or(AocError)?.extract(); // XXX
-            Ok(Brick(
+            let brick = Brick(
                 (x0.parse()?, y0.parse()?, z0.parse()?),
                 (x1.parse()?, y1.parse()?, z1.parse()?),
-            ))
+            );
+            // The ground is at z = 0, so no brick can be there.
+            if brick.min_z() == 0 {
+                return Err(AocError.into());
+            }
+            Ok(brick)
         })
     }
 
@@ -156,90 +199,84 @@ impl Day22 {
 
     fn part1_impl(&self, input: &mut dyn io::Read) -> BoxResult<Output> {
         let mut bricks = Self::parse(input)?;
-        Self::drop(&mut bricks);
-        let support_map = Self::make_support_map(&bricks);
-        Ok(bricks
-            .iter()
-            .filter(|brick| (*brick).is_redundant(&support_map))
+        let (_, graph) = Self::settle(&mut bricks)?;
+        Ok((0..bricks.len())
+            .filter(|&brick| graph.is_redundant(brick))
             .count())
     }
 
-    fn drop_broken(bricks: &mut [Brick], support_map: &SupportMap) {
-        for brick in bricks.iter_mut() {
-            // XXX BROKEN, see definition
-            brick.drop(support_map);
+    // Let the bricks fall, lowest first, keeping track of the top brick over every x/y position.
+    // Returns the number of bricks which moved, and which bricks ended up supporting which.
+    // Bricks overlapping each other in the snapshot are rejected, as they cannot be stacked.
+    pub fn settle(bricks: &mut [Brick]) -> BoxResult<(Output, SupportGraph)> {
+        let mut cubes = HashSet::new();
+        if !bricks
+            .iter()
+            .flat_map(|brick| brick.cubes())
+            .all(|cube| cubes.insert(cube))
+        {
+            return Err(AocError.into());
         }
-    }
-
-    fn drop(bricks: &mut Vec<Brick>) -> Output {
-        // XXX Would have loved to do this as Brick::drop but could not due to mutability and borrowing.
-        // XXX Also this should be done functionally!
-        let mut cnt = 0;
-        for i in 0..bricks.len() {
-            let mut do_drop = true;
-            let mut did_drop = false;
-            while do_drop {
-                let z = bricks[i].min_z();
-                if z == 1 {
-                    do_drop = false;
-                    continue;
-                }
-                for j in 0..bricks.len() {
-                    if i != j && bricks[j].max_z() == z - 1 && bricks[i].covers(&bricks[j]) {
-                        // println!("will not drop {:?} due to {:?}", bricks[i], bricks[j]);
-                        do_drop = false;
-                        break;
-                    }
-                }
-                if do_drop {
-                    did_drop = true;
-                    bricks[i].drop_one();
-                }
+        bricks.sort();
+        let mut top: HashMap<(usize, usize), (usize, usize)> = HashMap::new();
+        let mut graph = SupportGraph {
+            supports: vec![vec![]; bricks.len()],
+            rests_on: vec![vec![]; bricks.len()],
+        };
+        let mut moved = 0;
+        for (i, brick) in bricks.iter_mut().enumerate() {
+            let below = brick
+                .footprint()
+                .flat_map(|xy| top.get(&xy))
+                .copied()
+                .collect_vec();
+            let floor = below.iter().map(|(z, _)| *z).max().unwrap_or(0);
+            let fall = brick.min_z() - floor - 1;
+            if fall > 0 {
+                brick.0 .2 -= fall;
+                brick.1 .2 -= fall;
+                moved += 1;
             }
-            if did_drop {
-                cnt += 1;
+            graph.rests_on[i] = below
+                .into_iter()
+                .filter(|(z, _)| *z == floor)
+                .map(|(_, other)| other)
+                .sorted()
+                .dedup()
+                .collect_vec();
+            for &other in &graph.rests_on[i] {
+                graph.supports[other].push(i);
+            }
+            for xy in brick.footprint() {
+                top.insert(xy, (brick.max_z(), i));
             }
         }
-        bricks.sort();
-        cnt
-    }
-
-    fn make_support_map(bricks: &Vec<Brick>) -> SupportMap {
-        let support_map = bricks
-            .iter()
-            .fold(HashMap::new(), |mut support_map, brick| {
-                for other in bricks {
-                    if other.min_z() == brick.max_z() + 1 && other.covers(brick) {
-                        support_map
-                            .entry(*brick)
-                            .or_insert(HashSet::new())
-                            .insert(*other);
-                    }
-                }
-                support_map
-            });
-        //println!("support_map: {:?}", support_map);
-        support_map
+        Ok((moved, graph))
     }
 
-    fn disintegrate(brick: &Brick, mut bricks: Vec<Brick>) -> Output {
-        // println!("disintegrate {:?} among {}", brick, bricks.len());
-        bricks.retain(|other| brick != other);
-        // println!("  new count {}", bricks.len());
-        let rv = Self::drop(&mut bricks);
-        // println!("  -> {}", rv);
-        rv
+    // The impact of each settled brick, in settling order.
+    fn report_impl(&self, input: &mut dyn io::Read) -> BoxResult<Vec<Impact>> {
+        let mut bricks = Self::parse(input)?;
+        let (_, graph) = Self::settle(&mut bricks)?;
+        Ok(bricks
+            .into_iter()
+            .zip(graph.falls())
+            .enumerate()
+            .map(|(id, (brick, falls))| Impact {
+                id,
+                brick,
+                supports: graph.supports(id).to_vec(),
+                rests_on: graph.rests_on(id).to_vec(),
+                is_safe: graph.is_redundant(id),
+                falls,
+            })
+            .collect())
     }
 
     fn part2_impl(&self, input: &mut dyn io::Read) -> BoxResult<Output> {
         let mut bricks = Self::parse(input)?;
-        Self::drop(&mut bricks);
-        let support_map = Self::make_support_map(&bricks);
-        Ok(bricks
-            .iter()
-            .filter(|brick| !(*brick).is_redundant(&support_map))
-            .map(|brick| Self::disintegrate(brick, bricks.clone()))
-            .sum())
+        let (_, graph) = Self::settle(&mut bricks)?;
+        Ok(graph.falls().into_iter().sum())
     }
 }
 
@@ -251,35 +288,114 @@ mod tests {
         assert_eq!(Day22 {}.part1_impl(&mut s.as_bytes()).ok(), Some(f));
     }
 
-    #[test]
-    fn part1() {
-        test1(
-            "1,0,1~1,2,1
+    const EXAMPLE: &str = "1,0,1~1,2,1
 0,0,2~2,0,2
 0,2,3~2,2,3
 0,0,4~0,2,4
 2,0,5~2,2,5
 0,1,6~2,1,6
-1,1,8~1,1,9",
-            5,
-        );
+1,1,8~1,1,9";
+
+    #[test]
+    fn part1() {
+        test1(EXAMPLE, 5);
     }
 
     fn test2(s: &str, f: Output) {
         assert_eq!(Day22 {}.part2_impl(&mut s.as_bytes()).ok(), Some(f));
     }
 
+    #[test]
+    fn ground() {
+        assert!(Day22 {}.part1_impl(&mut "0,0,0~0,0,0".as_bytes()).is_err());
+        assert!(Day22 {}.part1_impl(&mut "0,0,1~0,0,0".as_bytes()).is_err());
+        test1("0,0,1~0,0,1", 1);
+        // Overlapping bricks cannot be settled.
+        assert!(Day22 {}
+            .part1_impl(&mut "0,0,1~0,0,3\n0,0,2~0,0,2".as_bytes())
+            .is_err());
+        assert!(Day22 {}
+            .part2_impl(&mut "0,0,5~0,0,7\n0,0,6~1,0,6".as_bytes())
+            .is_err());
+    }
+
     #[test]
     fn part2() {
-        crate::day22::tests::test2(
-            "1,0,1~1,2,1
-0,0,2~2,0,2
-0,2,3~2,2,3
-0,0,4~0,2,4
-2,0,5~2,2,5
-0,1,6~2,1,6
-1,1,8~1,1,9",
-            7,
+        crate::day22::tests::test2(EXAMPLE, 7);
+    }
+
+    #[test]
+    fn support() {
+        let mut bricks = Day22::parse(&mut EXAMPLE.as_bytes()).unwrap();
+        let (moved, graph) = Day22::settle(&mut bricks).unwrap();
+        assert_eq!(moved, 5);
+        assert_eq!(bricks[6], Brick((1, 1, 5), (1, 1, 6)));
+        assert_eq!(graph.supports(0), &[1, 2]);
+        assert_eq!(graph.rests_on(0), &[] as &[usize]);
+        assert_eq!(graph.rests_on(3), &[1, 2]);
+        assert_eq!(graph.supports(5), &[6]);
+        assert_eq!(
+            (0..7).map(|brick| graph.is_redundant(brick)).collect_vec(),
+            vec![false, true, true, true, true, false, true]
         );
+        assert_eq!(graph.falls(), vec![6, 0, 0, 0, 0, 1, 0]);
+    }
+
+    #[test]
+    fn report() {
+        let mut report = Day22 {}.report_impl(&mut EXAMPLE.as_bytes()).unwrap();
+        assert_eq!(report.len(), 7);
+        assert_eq!(
+            report[1],
+            Impact {
+                id: 1,
+                brick: Brick((0, 0, 2), (2, 0, 2)),
+                supports: vec![3, 4],
+                rests_on: vec![0],
+                is_safe: true,
+                falls: 0,
+            }
+        );
+        report.sort_by_key(|entry| Reverse(entry.falls));
+        assert_eq!(
+            report
+                .iter()
+                .take(2)
+                .map(|entry| (entry.brick.to_string(), entry.is_safe, entry.falls))
+                .collect_vec(),
+            vec![
+                ("1,0,1~1,2,1".to_string(), false, 6),
+                ("0,1,4~2,1,4".to_string(), false, 1)
+            ]
+        );
+    }
+
+    #[test]
+    fn large() {
+        assert_eq!(
+            Day22::parse(&mut "10,20,300~12,20,300".as_bytes()).unwrap(),
+            vec![Brick((10, 20, 300), (12, 20, 300))]
+        );
+        // A tower of long bricks crossing at (50, 50), next to two pillars holding up a plate.
+        let mut s = (0..50)
+            .map(|i| {
+                let z = 3 * i + 2;
+                if i % 2 == 0 {
+                    format!("0,50,{}~99,50,{}", z, z)
+                } else {
+                    format!("50,0,{}~50,99,{}", z, z)
+                }
+            })
+            .join("\n");
+        s += "\n150,150,1~150,150,60\n180,10,1~180,10,60\n0,0,500~199,199,500";
+        let mut bricks = Day22::parse(&mut s.as_bytes()).unwrap();
+        let (moved, graph) = Day22::settle(&mut bricks).unwrap();
+        assert_eq!(moved, 51);
+        assert_eq!(bricks.iter().map(|brick| brick.max_z()).max(), Some(61));
+        test1(&s, 4);
+        test2(&s, 49 * 50 / 2);
+        let plate = bricks.len() - 1;
+        assert_eq!(graph.rests_on(plate).len(), 2);
+        assert_eq!(graph.falls()[plate], 0);
     }
 }
@@ -1,8 +1,7 @@
-use petgraph::algo::all_simple_paths;
-use petgraph::dot::Dot;
-use petgraph::graph::UnGraph;
-use petgraph::prelude::UnGraphMap;
-use std::collections::{HashMap, HashSet, VecDeque};
+use petgraph::dot::{Config, Dot};
+use petgraph::graphmap::GraphMap;
+use petgraph::{Directed, EdgeType, Undirected};
+use std::collections::HashMap;
 use std::fmt::Debug;
 
 use crate::day::*;
@@ -23,12 +22,19 @@ impl Day for Day23 {
     fn part2(&self, input: &dyn Fn() -> Box<dyn io::Read>) {
         println!("{:?}", self.part2_impl(&mut *input()));
     }
+
+    fn dot(&self, input: &dyn Fn() -> Box<dyn io::Read>) {
+        match self.dot_impl(&mut *input()) {
+            Ok(dot) => print!("{}", dot),
+            Err(e) => println!("{:?}", e),
+        }
+    }
 }
 #[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
-struct Coord(isize, isize);
+pub struct Coord(pub isize, pub isize);
 
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
-enum Dir {
+pub enum Dir {
     East,
     South,
     West,
@@ -66,8 +72,7 @@ impl Coord {
         self,
         tiles: &HashMap<Coord, Tile>,
         size: Coord,
-        is_slippery: Option<bool>,
-        stop: Coord,
+        is_slippery: bool,
     ) -> Vec<(Dir, Coord)> {
         [Dir::East, Dir::South, Dir::West, Dir::North]
             .into_iter()
@@ -75,8 +80,7 @@ impl Coord {
                 self.walk(dir, size)
                     .filter(|coord| match tiles.get(coord) {
                         Some(Tile::Path) => true,
-                        Some(Tile::Slope(slope)) => is_slippery
-                            .map_or(*coord == stop, |is_slippery| !is_slippery || dir == *slope),
+                        Some(Tile::Slope(slope)) => !is_slippery || dir == *slope,
                         _ => false,
                     })
                     .map(|coord| (dir, coord))
@@ -86,64 +90,154 @@ impl Coord {
 }
 
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
-enum Tile {
+pub enum Tile {
     Path,
     Forest,
     Slope(Dir),
 }
 
-fn build_graph(
+// Compress the trails of a grid into a graph of junctions, i.e. the start, the stop and every
+// tile with more than two neighbouring trail tiles, with the corridors between them as edges
+// weighted by their length.  Dead ends are dropped.  The slopes are respected if the graph type
+// is directed, and ignored if undirected.  Where several corridors join the same pair of
+// junctions, the longest is kept.
+pub fn build_graph<Ty: EdgeType>(
     tiles: &HashMap<Coord, Tile>,
     size: Coord,
     start: Coord,
     stop: Coord,
-) -> UnGraphMap<Coord, Output> {
-    (0..)
-        .try_fold(
-            (UnGraphMap::new(), VecDeque::from([(start, None, start, 0)])),
-            |(mut graph, mut queue), _| {
-                if let Some((coord, previous, mut last_node, mut weight)) = queue.pop_front() {
-                    if coord == stop {
-                        graph.add_edge(last_node, stop, weight);
-                        Ok((graph, queue))
-                    } else {
-                        let mut neighbors = coord.moves(tiles, size, Some(false), stop);
-                        neighbors.retain(|(_, coord)| Some(*coord) != previous);
-                        if neighbors.len() > 1 {
-                            graph.add_edge(last_node, coord, weight);
-                            last_node = coord;
-                            weight = 0;
-                        }
-                        Ok(neighbors.iter().fold(
-                            (graph, queue),
-                            |(mut graph, mut queue), (_, next)| {
-                                if graph.contains_node(*next) {
-                                    graph.add_edge(last_node, *next, weight + 1);
-                                    queue.remove(
-                                        queue
-                                            .iter()
-                                            .position(|(c, _, _, _)| *c == coord)
-                                            .unwrap_or_else(|| {
-                                                panic!("missing {:?} in queue", coord)
-                                            }),
-                                    );
-                                } else {
-                                    queue.push_front((*next, Some(coord), last_node, weight + 1));
-                                }
-                                (graph, queue)
-                            },
-                        ))
-                    }
-                } else {
-                    Err(graph)
+) -> GraphMap<Coord, Output, Ty> {
+    let is_junction =
+        |coord: Coord| coord == start || coord == stop || coord.moves(tiles, size, false).len() > 2;
+    let junctions = tiles
+        .keys()
+        .copied()
+        .filter(|&coord| tiles.get(&coord) != Some(&Tile::Forest) && is_junction(coord))
+        .sorted()
+        .collect_vec();
+    let mut graph = GraphMap::new();
+    for &junction in &junctions {
+        graph.add_node(junction);
+    }
+    for &junction in &junctions {
+        for (_, first) in junction.moves(tiles, size, Ty::is_directed()) {
+            let (mut previous, mut coord, mut length) = (junction, first, 1);
+            while !is_junction(coord) {
+                match coord
+                    .moves(tiles, size, Ty::is_directed())
+                    .into_iter()
+                    .find(|(_, next)| *next != previous)
+                {
+                    Some((_, next)) => (previous, coord, length) = (coord, next, length + 1),
+                    None => break,
                 }
-            },
-        )
-        .unwrap_err()
+            }
+            if is_junction(coord)
+                && coord != junction
+                && graph
+                    .edge_weight(junction, coord)
+                    .is_none_or(|&weight| weight < length)
+            {
+                graph.add_edge(junction, coord, length);
+            }
+        }
+    }
+    graph
+}
+
+// Find the longest simple path from start to stop in a compressed trail graph, returning its
+// length and the junctions along it.  This is a depth-first search over a bitmask of visited
+// junctions, pruned by an upper bound on what the unvisited junctions can still add.  As the
+// stop is usually reached through a single junction, arriving there forces the last step.
+pub fn longest_path<Ty: EdgeType>(
+    graph: &GraphMap<Coord, Output, Ty>,
+    start: Coord,
+    stop: Coord,
+) -> BoxResult<(Output, Vec<Coord>)> {
+    let nodes = graph.nodes().collect_vec();
+    if nodes.len() > u128::BITS as usize {
+        return Err(AocError.into());
+    }
+    let index = |coord| nodes.iter().position(|&c| c == coord).ok_or(AocError);
+    let (start, stop) = (index(start)?, index(stop)?);
+    let adjacent = nodes
+        .iter()
+        .map(|&node| {
+            graph
+                .edges(node)
+                .map(|(_, next, &weight)| (index(next).unwrap(), weight))
+                .collect_vec()
+        })
+        .collect_vec();
+    // Any path enters each junction at most once, over at most its heaviest edge.
+    let mut heaviest = vec![0; nodes.len()];
+    for (next, weight) in adjacent.iter().flatten() {
+        heaviest[*next] = heaviest[*next].max(*weight);
+    }
+    let last = adjacent
+        .iter()
+        .positions(|edges| edges.iter().any(|(next, _)| *next == stop))
+        .exactly_one()
+        .ok();
+
+    struct Search<'a> {
+        adjacent: &'a [Vec<(usize, Output)>],
+        heaviest: &'a [Output],
+        stop: usize,
+        last: Option<usize>,
+        path: Vec<usize>,
+        best: Option<(Output, Vec<usize>)>,
+    }
+
+    impl Search<'_> {
+        fn dfs(&mut self, node: usize, visited: u128, length: Output, remaining: Output) {
+            if node == self.stop {
+                if self.best.as_ref().is_none_or(|(best, _)| length > *best) {
+                    self.best = Some((length, self.path.clone()));
+                }
+                return;
+            }
+            if self
+                .best
+                .as_ref()
+                .is_some_and(|(best, _)| length + remaining <= *best)
+            {
+                return;
+            }
+            for &(next, weight) in &self.adjacent[node] {
+                if visited & 1 << next != 0 || Some(node) == self.last && next != self.stop {
+                    continue;
+                }
+                self.path.push(next);
+                self.dfs(
+                    next,
+                    visited | 1 << next,
+                    length + weight,
+                    remaining - self.heaviest[next],
+                );
+                self.path.pop();
+            }
+        }
+    }
+
+    let mut search = Search {
+        adjacent: &adjacent,
+        heaviest: &heaviest,
+        stop,
+        last,
+        path: vec![start],
+        best: None,
+    };
+    let remaining = heaviest.iter().sum::<Output>() - heaviest[start];
+    search.dfs(start, 1 << start, 0, remaining);
+    search
+        .best
+        .map(|(length, path)| (length, path.into_iter().map(|i| nodes[i]).collect_vec()))
+        .ok_or(AocError.into())
 }
 
 impl Day23 {
-    fn parse(input: &mut dyn io::Read) -> BoxResult<(HashMap<Coord, Tile>, Coord)> {
+    pub fn parse(input: &mut dyn io::Read) -> BoxResult<(HashMap<Coord, Tile>, Coord)> {
         io::BufReader::new(input).lines().enumerate().try_fold(
             (HashMap::new(), Coord(0, 0)),
             |(mut map, mut size), (y, rs)| {
@@ -174,75 +268,41 @@ impl Day23 {
         )
     }
 
-    fn track(
-        tiles: &HashMap<Coord, Tile>,
-        size: Coord,
-        start: Coord,
-        stop: Coord,
-        is_slippery: Option<bool>,
-        mut seen: HashSet<Coord>,
-    ) -> Vec<HashSet<Coord>> {
-        seen.insert(start);
-        (1..)
-            .try_fold((vec![(start, seen)], vec![]), |(states, mut seens), i| {
-                let states = states
-                    .into_iter()
-                    .flat_map(|(coord, mut seen)| {
-                        seen.insert(coord);
-                        coord
-                            .moves(tiles, size, is_slippery, stop)
-                            .into_iter()
-                            .map(|(_, coord)| {
-                                if coord == stop {
-                                    seens.push(seen.clone());
-                                }
-                                coord
-                            })
-                            .filter(|coord| !seen.contains(coord))
-                            .map(|coord| (coord, seen.clone()))
-                            .collect_vec()
-                    })
-                    .collect_vec();
-                if states.is_empty() {
-                    Err(seens)
-                } else {
-                    Ok((states, seens))
-                }
-            })
-            .unwrap_err()
+    fn part1_impl(&self, input: &mut dyn io::Read) -> BoxResult<Output> {
+        let (tiles, size) = Self::parse(input)?;
+        let start = Coord(1, 0);
+        let stop = Coord(size.0 - 2, size.1 - 1);
+        let graph = build_graph::<Directed>(&tiles, size, start, stop);
+        longest_path(&graph, start, stop).map(|(length, _)| length)
     }
 
-    fn part1_impl(&self, input: &mut dyn io::Read) -> BoxResult<Output> {
+    // Render the compressed trail graph in Graphviz DOT format, with the start and stop marked.
+    fn dot_impl(&self, input: &mut dyn io::Read) -> BoxResult<String> {
         let (tiles, size) = Self::parse(input)?;
-        Self::track(
-            &tiles,
-            size,
-            Coord(1, 0),
-            Coord(size.0 - 2, size.1 - 1),
-            Some(true),
-            HashSet::new(),
-        )
-        .into_iter()
-        .map(|seen| seen.len())
-        .max()
-        .ok_or(AocError.into())
+        let start = Coord(1, 0);
+        let stop = Coord(size.0 - 2, size.1 - 1);
+        let graph = build_graph::<Undirected>(&tiles, size, start, stop);
+        Ok(format!(
+            "{:?}",
+            Dot::with_attr_getters(
+                &graph,
+                &[Config::EdgeNoLabel],
+                &|_, (_, _, weight)| format!("label = \"{}\"", weight),
+                &|_, (coord, _)| if coord == start || coord == stop {
+                    "shape = doublecircle".to_string()
+                } else {
+                    String::new()
+                },
+            )
+        ))
     }
 
     fn part2_impl(&self, input: &mut dyn io::Read) -> BoxResult<Output> {
         let (tiles, size) = Self::parse(input)?;
         let start = Coord(1, 0);
-        let stop = Coord(size.1 - 2, size.1 - 1);
-        let graph = build_graph(&tiles, size, start, stop);
-        //println!("{:?}", Dot::new(&graph));
-        all_simple_paths::<Vec<_>, _>(&graph, start, stop, 0, None)
-            .map(|path| {
-                path.into_iter()
-                    .tuple_windows()
-                    .flat_map(|(a, b)| graph.edge_weight(a, b))
-                    .sum()
-            })
-            .max()
-            .ok_or(AocError.into())
+        let stop = Coord(size.0 - 2, size.1 - 1);
+        let graph = build_graph::<Undirected>(&tiles, size, start, stop);
+        longest_path(&graph, start, stop).map(|(length, _)| length)
     }
 }
 
@@ -250,14 +310,7 @@ impl Day23 {
 mod tests {
     use super::*;
 
-    fn test1(s: &str, f: Output) {
-        assert_eq!(Day23 {}.part1_impl(&mut s.as_bytes()).ok(), Some(f));
-    }
-
-    #[test]
-    fn part1() {
-        test1(
-            "#.#####################
+    const EXAMPLE: &str = "#.#####################
 #.......#########...###
 #######.#########.#.###
 ###.....#.>.>.###.#.###
@@ -279,9 +332,15 @@ mod tests {
 #...#...#.#.>.>.#.>.###
 #.###.###.#.###.#.#v###
 #.....###...###...#...#
-#####################.#",
-            94,
-        );
+#####################.#";
+
+    fn test1(s: &str, f: Output) {
+        assert_eq!(Day23 {}.part1_impl(&mut s.as_bytes()).ok(), Some(f));
+    }
+
+    #[test]
+    fn part1() {
+        test1(EXAMPLE, 94);
     }
 
     fn test2(s: &str, f: Output) {
@@ -290,31 +349,73 @@ mod tests {
 
     #[test]
     fn part2() {
-        test2(
-            "#.#####################
-#.......#########...###
-#######.#########.#.###
-###.....#.>.>.###.#.###
-###v#####.#v#.###.#.###
-###.>...#.#.#.....#...#
-###v###.#.#.#########.#
-###...#.#.#.......#...#
-#####.#.#.#######.#.###
-#.....#.#.#.......#...#
-#.#####.#.#.#########v#
-#.#...#...#...###...>.#
-#.#.#v#######v###.###v#
-#...#.>.#...>.>.#.###.#
-#####v#.#.###v#.#.###.#
-#.....#...#...#.#.#...#
-#.#########.###.#.#.###
-#...###...#...#...#.###
-###.###.#.###v#####v###
-#...#...#.#.>.>.#.>.###
-#.###.###.#.###.#.#v###
-#.....###...###...#...#
-#####################.#",
-            154,
+        test2(EXAMPLE, 154);
+    }
+
+    #[test]
+    fn path() {
+        let (tiles, size) = Day23::parse(&mut EXAMPLE.as_bytes()).unwrap();
+        let (start, stop) = (Coord(1, 0), Coord(21, 22));
+        let graph = build_graph::<Undirected>(&tiles, size, start, stop);
+        let (length, path) = longest_path(&graph, start, stop).unwrap();
+        assert_eq!(length, 154);
+        assert_eq!(path.len(), 9);
+        assert_eq!(path.first(), Some(&start));
+        assert_eq!(path.last(), Some(&stop));
+        assert!(path.iter().all_unique());
+        assert_eq!(
+            path.iter()
+                .tuple_windows()
+                .map(|(a, b)| graph.edge_weight(*a, *b).unwrap())
+                .sum::<Output>(),
+            154
         );
+        assert!(longest_path(&graph, start, Coord(0, 0)).is_err());
+    }
+
+    #[test]
+    fn graph() {
+        let (tiles, size) = Day23::parse(&mut EXAMPLE.as_bytes()).unwrap();
+        let (start, stop) = (Coord(1, 0), Coord(21, 22));
+        let undirected = build_graph::<Undirected>(&tiles, size, start, stop);
+        assert_eq!(undirected.node_count(), 9);
+        assert_eq!(undirected.edge_count(), 12);
+        assert_eq!(undirected.edge_weight(start, Coord(3, 5)), Some(&15));
+        let directed = build_graph::<Directed>(&tiles, size, start, stop);
+        assert_eq!(directed.node_count(), 9);
+        assert_eq!(directed.edge_count(), 12);
+        assert_eq!(directed.edge_weight(start, Coord(3, 5)), Some(&15));
+        assert_eq!(directed.edge_weight(Coord(3, 5), start), None);
+        assert_eq!(longest_path(&directed, start, stop).unwrap().0, 94);
+
+        // Any endpoints will do, on a grid which is not square.
+        let (tiles, size) = Day23::parse(
+            &mut "#########
+#.......#
+#.##.##.#
+#.......#
+#########"
+                .as_bytes(),
+        )
+        .unwrap();
+        let (start, stop) = (Coord(1, 1), Coord(7, 3));
+        let graph = build_graph::<Undirected>(&tiles, size, start, stop);
+        assert_eq!(graph.node_count(), 4);
+        assert_eq!(graph.edge_count(), 5);
+        assert_eq!(
+            longest_path(&graph, start, stop).unwrap(),
+            (12, vec![start, Coord(4, 3), Coord(4, 1), stop])
+        );
+        assert_eq!(longest_path(&graph, stop, start).unwrap().0, 12);
+    }
+
+    #[test]
+    fn dot() {
+        let dot = Day23 {}.dot_impl(&mut EXAMPLE.as_bytes()).unwrap();
+        assert!(dot.starts_with("graph {"));
+        assert!(dot.contains("label = \"Coord(1, 0)\" shape = doublecircle"));
+        assert!(dot.contains("label = \"Coord(21, 22)\" shape = doublecircle"));
+        assert!(dot.contains("label = \"15\""));
+        assert_eq!(dot.matches(" -- ").count(), 12);
     }
 }
@@ -1,10 +1,8 @@
-use graphalgs::connect::find_bridges;
-use petgraph::algo::has_path_connecting;
+use petgraph::dot::{Config, Dot};
 use petgraph::graphmap::UnGraphMap;
 use regex::Regex;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::fmt::Debug;
-use std::hash::{Hash, Hasher};
 
 use crate::day::*;
 
@@ -18,18 +16,144 @@ impl Day for Day25 {
     }
 
     fn part1(&self, input: &dyn Fn() -> Box<dyn io::Read>) {
-        println!("{:?}", self.part1_impl(&mut *input()));
+        let result = self.part1_impl(&mut *input());
+        println!("{:?}", result.as_ref().map(|(product, _)| product));
+        if let Ok((_, cut)) = result {
+            println!(
+                "cut: {}",
+                cut.edges
+                    .iter()
+                    .map(|(a, b)| format!("{}/{}", a, b))
+                    .join(", ")
+            );
+            println!("{}: {}", cut.left.len(), cut.left.join(" "));
+            println!("{}: {}", cut.right.len(), cut.right.join(" "));
+        }
     }
 
     fn part2(&self, input: &dyn Fn() -> Box<dyn io::Read>) {
         println!("{:?}", self.part2_impl(&mut *input()));
     }
+
+    fn dot(&self, input: &dyn Fn() -> Box<dyn io::Read>) {
+        match self.dot_impl(&mut *input()) {
+            Ok(dot) => print!("{}", dot),
+            Err(e) => println!("{:?}", e),
+        }
+    }
 }
 
 lazy_static! {
     static ref PATTERN: Regex = Regex::new("^(.*): (.*)$").unwrap();
 }
 
+// A global minimum cut of a graph: the number of edges cut, the edges themselves and the two
+// node sets they separate.
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct Cut {
+    size: usize,
+    edges: Vec<(String, String)>,
+    left: Vec<String>,
+    right: Vec<String>,
+}
+
+// Find a global minimum cut with the Stoer-Wagner algorithm.  Each of the V - 1 phases runs a
+// maximum adjacency search in O(E + W), W being the total edge weight.  Parallel edges add to
+// the weight between their endpoints.  Returns None if there are fewer than two nodes.
+fn min_cut(edges: &HashSet<(String, String)>) -> Option<Cut> {
+    let nodes = edges
+        .iter()
+        .flat_map(|(a, b)| [a.as_str(), b.as_str()])
+        .sorted()
+        .dedup()
+        .collect_vec();
+    if nodes.len() < 2 {
+        return None;
+    }
+    let index = nodes
+        .iter()
+        .enumerate()
+        .map(|(i, &node)| (node, i))
+        .collect::<HashMap<_, _>>();
+    let mut adjacent = vec![HashMap::new(); nodes.len()];
+    for (a, b) in edges.iter().filter(|(a, b)| a != b) {
+        let (a, b) = (index[a.as_str()], index[b.as_str()]);
+        *adjacent[a].entry(b).or_insert(0) += 1;
+        *adjacent[b].entry(a).or_insert(0) += 1;
+    }
+    // The original nodes merged into each remaining node.
+    let mut members = (0..nodes.len()).map(|i| vec![i]).collect_vec();
+    let mut alive = (0..nodes.len()).collect_vec();
+    let mut best: Option<(usize, Vec<usize>)> = None;
+    let mut key = vec![0; nodes.len()];
+    let mut added = vec![false; nodes.len()];
+    while alive.len() > 1 {
+        // The weights are integers which only grow during a phase, so a bucket queue with lazy
+        // deletion gives the most tightly connected node in amortised constant time.
+        for &i in &alive {
+            key[i] = 0;
+            added[i] = false;
+        }
+        let mut buckets = vec![alive.clone()];
+        let mut top = 0;
+        let (mut s, mut t, mut weight) = (alive[0], alive[0], 0);
+        loop {
+            match buckets[top].pop() {
+                Some(u) if !added[u] && key[u] == top => {
+                    added[u] = true;
+                    (s, t, weight) = (t, u, top);
+                    for (&v, &w) in adjacent[u].iter().filter(|(v, _)| !added[**v]) {
+                        key[v] += w;
+                        if key[v] >= buckets.len() {
+                            buckets.resize(key[v] + 1, vec![]);
+                        }
+                        buckets[key[v]].push(v);
+                        top = top.max(key[v]);
+                    }
+                }
+                Some(_) => {}
+                None if top > 0 => top -= 1,
+                None => break,
+            }
+        }
+        if best.as_ref().is_none_or(|(size, _)| weight < *size) {
+            best = Some((weight, members[t].clone()));
+        }
+        // Merge t into s.
+        for (v, w) in std::mem::take(&mut adjacent[t]) {
+            adjacent[v].remove(&t);
+            if v != s {
+                *adjacent[s].entry(v).or_insert(0) += w;
+                *adjacent[v].entry(s).or_insert(0) += w;
+            }
+        }
+        let merged = std::mem::take(&mut members[t]);
+        members[s].extend(merged);
+        alive.retain(|&i| i != t);
+    }
+    best.map(|(size, left)| {
+        let left = left.into_iter().map(|i| nodes[i]).collect::<HashSet<_>>();
+        let edges = edges
+            .iter()
+            .filter(|(a, b)| left.contains(a.as_str()) != left.contains(b.as_str()))
+            .cloned()
+            .sorted()
+            .collect_vec();
+        let (left, right): (Vec<_>, Vec<_>) =
+            nodes.into_iter().partition(|node| left.contains(node));
+        let (left, right) = (
+            left.into_iter().map(ToString::to_string).collect_vec(),
+            right.into_iter().map(ToString::to_string).collect_vec(),
+        );
+        Cut {
+            size,
+            edges,
+            left,
+            right,
+        }
+    })
+}
+
 impl Day25 {
     fn parse_wiring(spec: BoxResult<String>) -> BoxResult<HashSet<(String, String)>> {
         spec.and_then(|s| {
@@ -52,37 +176,25 @@ impl Day25 {
             })
     }
 
-    fn part1_impl(&self, input: &mut dyn io::Read) -> BoxResult<Output> {
+    // Returns the product of the component sizes, together with the cut separating them.
+    fn part1_impl(&self, input: &mut dyn io::Read) -> BoxResult<(Output, Cut)> {
+        let bonds = Self::parse(input)?;
+        min_cut(&bonds)
+            .map(|cut| (cut.left.len() * cut.right.len(), cut))
+            .ok_or(AocError.into())
+    }
+
+    // Render the wiring graph in Graphviz DOT format.
+    fn dot_impl(&self, input: &mut dyn io::Read) -> BoxResult<String> {
         let bonds = Self::parse(input)?;
         let mut graph = UnGraphMap::new();
         for (a, b) in &bonds {
-            graph.add_edge(a, b, "");
+            graph.add_edge(a.as_str(), b.as_str(), "");
         }
-        bonds
-            .iter()
-            .tuple_combinations()
-            .flat_map(|(e1 @ (a1, b1), e2 @ (a2, b2))| {
-                let mut graph = graph.clone();
-                graph.remove_edge(a1, b1);
-                graph.remove_edge(a2, b2);
-                let bridges = find_bridges(&graph);
-                bridges.first().map(|e3| (e1, e2, *e3))
-            })
-            .next()
-            .map(|(a, b, c)| {
-                graph.remove_edge(&a.0, &a.1);
-                graph.remove_edge(&b.0, &b.1);
-                graph.remove_edge(c.0, c.1);
-                let (left, right) = graph.nodes().fold((0, 0), |(left, right), node| {
-                    if has_path_connecting(&graph, node, &a.0, None) {
-                        (left + 1, right)
-                    } else {
-                        (left, right + 1)
-                    }
-                });
-                left * right
-            })
-            .ok_or(AocError.into())
+        Ok(format!(
+            "{}",
+            Dot::with_config(&graph, &[Config::EdgeNoLabel])
+        ))
     }
 
     fn part2_impl(&self, _input: &mut dyn io::Read) -> BoxResult<Output> {
@@ -95,13 +207,16 @@ mod tests {
     use super::*;
 
     fn test1(s: &str, f: Output) {
-        assert_eq!(Day25 {}.part1_impl(&mut s.as_bytes()).ok(), Some(f));
+        assert_eq!(
+            Day25 {}
+                .part1_impl(&mut s.as_bytes())
+                .ok()
+                .map(|(product, _)| product),
+            Some(f)
+        );
     }
 
-    #[test]
-    fn part1() {
-        test1(
-            "jqt: rhn xhk nvd
+    const EXAMPLE: &str = "jqt: rhn xhk nvd
 rsh: frs pzl lsr
 xhk: hfx
 cmg: qnr nvd lhk bvb
@@ -114,8 +229,80 @@ nvd: lhk
 lsr: lhk
 rzs: qnr cmg lsr rsh
 frs: qnr lhk lsr
-",
-            54,
+";
+
+    #[test]
+    fn part1() {
+        test1(EXAMPLE, 54);
+    }
+
+    #[test]
+    fn cut() {
+        let (_, cut) = Day25 {}.part1_impl(&mut EXAMPLE.as_bytes()).unwrap();
+        assert_eq!(cut.size, 3);
+        assert_eq!(
+            cut.edges
+                .iter()
+                .map(|(a, b)| a.min(b).to_string() + "/" + a.max(b))
+                .sorted()
+                .collect_vec(),
+            vec!["bvb/cmg", "hfx/pzl", "jqt/nvd"]
+        );
+        let (small, large) = if cut.left.len() < cut.right.len() {
+            (cut.left, cut.right)
+        } else {
+            (cut.right, cut.left)
+        };
+        assert_eq!(small, vec!["bvb", "hfx", "jqt", "ntq", "rhn", "xhk"]);
+        assert_eq!(
+            large,
+            vec!["cmg", "frs", "lhk", "lsr", "nvd", "pzl", "qnr", "rsh", "rzs"]
         );
+        assert_eq!(min_cut(&HashSet::new()), None);
+    }
+
+    // Two rings where every node is also wired to its next few neighbours, joined by a handful
+    // of wires.
+    fn rings(n: usize, degree: usize, joins: usize) -> HashSet<(String, String)> {
+        let mut bonds = HashSet::new();
+        for ring in ["a", "b"] {
+            for i in 0..n {
+                for d in 1..=degree / 2 {
+                    bonds.insert((format!("{}{}", ring, i), format!("{}{}", ring, (i + d) % n)));
+                }
+            }
+        }
+        for i in 0..joins {
+            bonds.insert((format!("a{}", i * 7 % n), format!("b{}", i * 11 % n)));
+        }
+        bonds
+    }
+
+    #[test]
+    fn large_cut() {
+        for joins in [0, 1, 3, 5] {
+            let bonds = rings(250, 8, joins);
+            let cut = min_cut(&bonds).unwrap();
+            assert_eq!(cut.size, joins);
+            assert_eq!(cut.edges.len(), joins);
+            assert_eq!(cut.left.len(), 250);
+            assert_eq!(cut.right.len(), 250);
+            assert!(cut.left.iter().map(|node| &node[..1]).all_equal());
+        }
+    }
+
+    #[test]
+    fn dot() {
+        let dot = Day25 {}
+            .dot_impl(
+                &mut "jqt: rhn xhk
+rhn: xhk
+"
+                .as_bytes(),
+            )
+            .unwrap();
+        assert!(dot.starts_with("graph {"));
+        assert!(dot.contains("label = \"jqt\" ]"));
+        assert_eq!(dot.matches(" -- ").count(), 3);
     }
 }
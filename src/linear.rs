@@ -0,0 +1,82 @@
+use num::{BigRational, Zero};
+
+pub type Matrix = Vec<Vec<BigRational>>;
+
+// Solve the square linear system a * x = b exactly, by Gaussian elimination with back
+// substitution.  Returns None if the system is singular, i.e. has no unique solution.
+pub fn solve(mut a: Matrix, mut b: Vec<BigRational>) -> Option<Vec<BigRational>> {
+    let n = b.len();
+    if a.len() != n || a.iter().any(|row| row.len() != n) {
+        return None;
+    }
+    for col in 0..n {
+        // Any non-zero pivot will do, as the arithmetic is exact.
+        let pivot = (col..n).find(|&row| !a[row][col].is_zero())?;
+        a.swap(col, pivot);
+        b.swap(col, pivot);
+        let pivot = a[col].clone();
+        for row in col + 1..n {
+            if a[row][col].is_zero() {
+                continue;
+            }
+            let factor = &a[row][col] / &pivot[col];
+            for (value, p) in a[row].iter_mut().zip(&pivot).skip(col) {
+                *value -= &factor * p;
+            }
+            let delta = &factor * &b[col];
+            b[row] -= delta;
+        }
+    }
+    let mut x = vec![BigRational::zero(); n];
+    for row in (0..n).rev() {
+        let sum = (row + 1..n).fold(b[row].clone(), |sum, k| sum - &a[row][k] * &x[k]);
+        x[row] = sum / &a[row][row];
+    }
+    Some(x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matrix(rows: &[&[isize]]) -> Matrix {
+        rows.iter()
+            .map(|row| {
+                row.iter()
+                    .map(|&n| BigRational::from_integer(n.into()))
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn vector(values: &[isize]) -> Vec<BigRational> {
+        values
+            .iter()
+            .map(|&n| BigRational::from_integer(n.into()))
+            .collect()
+    }
+
+    #[test]
+    fn solve() {
+        // Needs a row swap, as the first pivot is zero.
+        assert_eq!(
+            super::solve(
+                matrix(&[&[0, 2, 1], &[1, 1, 1], &[2, 1, -1]]),
+                vector(&[7, 6, 1])
+            ),
+            Some(vector(&[1, 2, 3]))
+        );
+        assert_eq!(
+            super::solve(matrix(&[&[2, 0], &[0, 3]]), vector(&[1, 1])),
+            Some(vec![
+                BigRational::new(1.into(), 2.into()),
+                BigRational::new(1.into(), 3.into())
+            ])
+        );
+        assert_eq!(
+            super::solve(matrix(&[&[1, 2], &[2, 4]]), vector(&[1, 2])),
+            None
+        );
+        assert_eq!(super::solve(matrix(&[&[1, 2]]), vector(&[1])), None);
+    }
+}
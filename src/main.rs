@@ -7,6 +7,7 @@ extern crate lazy_static;
 // extern crate simple_error;
 
 //mod cpu;
+mod cycle;
 mod day;
 mod day01;
 mod day02;
@@ -33,6 +34,8 @@ mod day22;
 mod day23;
 mod day24;
 mod day25;
+mod linear;
+mod polygon;
 
 use crate::day::*;
 use std::env;
@@ -75,11 +78,22 @@ fn main() {
         }
         let input: Box<dyn Fn() -> Box<dyn io::Read>> =
             Box::new(|| Box::new(fs::File::open(&day.1).unwrap()));
+        if args.len() > 3 && args[3] == "dot" {
+            // Keep the output clean so it can be piped straight into Graphviz.
+            day.0.dot(&input);
+            continue;
+        }
+        if args.len() > 3 && args[3] == "svg" {
+            day.0.svg(&input);
+            continue;
+        }
         println!("= {} =", day.0.tag());
         if args.len() > 3 && args[3] == "1" {
             day.0.part1(&input);
         } else if args.len() > 3 && args[3] == "2" {
             day.0.part2(&input);
+        } else if args.len() > 3 && args[3] == "report" {
+            day.0.report(&input);
         } else {
             day.0.part1(&input);
             day.0.part2(&input);
@@ -0,0 +1,165 @@
+use itertools::Itertools;
+use num::integer::gcd;
+
+pub type Point = (isize, isize);
+
+// A closed polygon on the integer lattice, given by its vertices in order.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Polygon {
+    vertices: Vec<Point>,
+}
+
+fn cross(o: Point, a: Point, b: Point) -> isize {
+    (a.0 - o.0) * (b.1 - o.1) - (a.1 - o.1) * (b.0 - o.0)
+}
+
+fn dot(o: Point, a: Point, b: Point) -> isize {
+    (a.0 - o.0) * (b.0 - o.0) + (a.1 - o.1) * (b.1 - o.1)
+}
+
+// Whether p lies on the segment from a to b, given that the three are collinear.
+fn is_within(p: Point, (a, b): (Point, Point)) -> bool {
+    a.0.min(b.0) <= p.0 && p.0 <= a.0.max(b.0) && a.1.min(b.1) <= p.1 && p.1 <= a.1.max(b.1)
+}
+
+fn intersects(s: (Point, Point), t: (Point, Point)) -> bool {
+    let (d1, d2) = (cross(t.0, t.1, s.0), cross(t.0, t.1, s.1));
+    let (d3, d4) = (cross(s.0, s.1, t.0), cross(s.0, s.1, t.1));
+    d1.signum() * d2.signum() < 0 && d3.signum() * d4.signum() < 0
+        || d1 == 0 && is_within(s.0, t)
+        || d2 == 0 && is_within(s.1, t)
+        || d3 == 0 && is_within(t.0, s)
+        || d4 == 0 && is_within(t.1, s)
+}
+
+impl Polygon {
+    pub fn new(vertices: Vec<Point>) -> Self {
+        Polygon { vertices }
+    }
+
+    pub fn edges(&self) -> impl Iterator<Item = (Point, Point)> + '_ {
+        self.vertices.iter().copied().circular_tuple_windows()
+    }
+
+    // Twice the enclosed area, by the shoelace formula.  Twice, as a lattice polygon may have
+    // a half-integer area.
+    pub fn double_area(&self) -> isize {
+        self.edges()
+            .map(|(a, b)| a.0 * b.1 - b.0 * a.1)
+            .sum::<isize>()
+            .abs()
+    }
+
+    // The number of lattice points on the edges.
+    pub fn boundary(&self) -> isize {
+        self.edges().map(|(a, b)| gcd(b.0 - a.0, b.1 - a.1)).sum()
+    }
+
+    // The number of lattice points strictly inside, by Pick's theorem: A = I + B / 2 - 1.
+    pub fn interior(&self) -> isize {
+        (self.double_area() - self.boundary()) / 2 + 1
+    }
+
+    // The number of lattice points inside or on the edges.
+    pub fn lattice_points(&self) -> isize {
+        self.interior() + self.boundary()
+    }
+
+    // The area formulas only hold if no edges cross or touch, except consecutive edges at their
+    // common vertex, and no edge doubles back over the previous one.
+    pub fn is_simple(&self) -> bool {
+        let edges = self.edges().collect_vec();
+        let n = edges.len();
+        n >= 3
+            && edges
+                .iter()
+                .circular_tuple_windows()
+                .all(|(&(a, b), &(_, c))| cross(a, b, c) != 0 || dot(b, a, c) < 0)
+            && (0..n)
+                .tuple_combinations()
+                .all(|(i, j)| j == i + 1 || i == 0 && j == n - 1 || !intersects(edges[i], edges[j]))
+    }
+
+    // Render as an SVG image, with the y axis pointing down.
+    pub fn svg(&self) -> String {
+        let (min_x, max_x) = self
+            .vertices
+            .iter()
+            .map(|p| p.0)
+            .minmax()
+            .into_option()
+            .unwrap_or((0, 0));
+        let (min_y, max_y) = self
+            .vertices
+            .iter()
+            .map(|p| p.1)
+            .minmax()
+            .into_option()
+            .unwrap_or((0, 0));
+        let margin = (max_x - min_x).max(max_y - min_y) / 50 + 1;
+        format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"{} {} {} {}\">\n\
+             <polygon points=\"{}\" fill=\"lightgrey\" stroke=\"black\" \
+             vector-effect=\"non-scaling-stroke\"/>\n\
+             </svg>\n",
+            min_x - margin,
+            min_y - margin,
+            max_x - min_x + 2 * margin,
+            max_y - min_y + 2 * margin,
+            self.vertices
+                .iter()
+                .map(|(x, y)| format!("{},{}", x, y))
+                .join(" ")
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn area() {
+        let square = Polygon::new(vec![(0, 0), (4, 0), (4, 4), (0, 4)]);
+        assert_eq!(square.double_area(), 32);
+        assert_eq!(square.boundary(), 16);
+        assert_eq!(square.interior(), 9);
+        assert_eq!(square.lattice_points(), 25);
+        // Clockwise or not makes no difference.
+        let triangle = Polygon::new(vec![(0, 0), (0, 3), (3, 0)]);
+        assert_eq!(triangle.double_area(), 9);
+        assert_eq!(triangle.boundary(), 9);
+        assert_eq!(triangle.interior(), 1);
+        assert_eq!(triangle.lattice_points(), 10);
+    }
+
+    #[test]
+    fn simple() {
+        assert!(Polygon::new(vec![(0, 0), (2, 0), (4, 0), (4, 4), (0, 4)]).is_simple());
+        // A figure eight.
+        assert!(!Polygon::new(vec![(0, 0), (2, 2), (2, 0), (0, 2)]).is_simple());
+        // Touching itself at a vertex.
+        assert!(!Polygon::new(vec![
+            (0, 0),
+            (2, 0),
+            (2, 2),
+            (4, 2),
+            (4, 4),
+            (2, 4),
+            (2, 2),
+            (0, 2)
+        ])
+        .is_simple());
+        // Doubling back.
+        assert!(!Polygon::new(vec![(0, 0), (4, 0), (2, 0), (2, 2)]).is_simple());
+        assert!(!Polygon::new(vec![(0, 0), (1, 0)]).is_simple());
+    }
+
+    #[test]
+    fn svg() {
+        let svg = Polygon::new(vec![(0, 0), (4, 0), (4, 4), (0, 4)]).svg();
+        assert!(svg.starts_with("<svg "));
+        assert!(svg.contains("viewBox=\"-1 -1 6 6\""));
+        assert!(svg.contains("points=\"0,0 4,0 4,4 0,4\""));
+    }
+}